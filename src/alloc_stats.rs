@@ -0,0 +1,203 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::runner::RunResult;
+
+/// Allocator forwarding to [`System`] that counts allocations of the thread
+/// running [`CountingAllocator::measure`].
+///
+/// The binary installs it with `#[global_allocator]`, so every allocation pays
+/// for it, benchmarks included. While nothing is measured that is a single
+/// relaxed atomic load per call, during a measurement every thread also reads
+/// its thread local counters.
+pub struct CountingAllocator {
+    /// Number of measurements running on any thread.
+    active: AtomicUsize,
+}
+
+/// Allocations made during a measurement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub count: usize,
+    /// Total bytes requested, including reallocations.
+    pub bytes: usize,
+    /// Highest amount of memory in use above the level at the start.
+    pub peak: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Counters {
+    count: usize,
+    bytes: usize,
+    // Can go below zero when memory allocated before the measurement is freed.
+    current: isize,
+    peak: isize,
+}
+
+thread_local! {
+    /// Counters of the measurement running on this thread. Const initialized
+    /// without a destructor, so accessing it never allocates.
+    static MEASUREMENT: Cell<Option<Counters>> = const { Cell::new(None) };
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        CountingAllocator {
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// Runs `f` counting allocations it makes on the current thread. Threads
+    /// spawned by `f` and other threads running at the same time are not
+    /// counted.
+    pub fn measure<R>(&self, f: impl FnOnce() -> R) -> (R, AllocStats) {
+        self.active.fetch_add(1, Ordering::SeqCst);
+        let outer = MEASUREMENT.replace(Some(Counters::default()));
+
+        let res = f();
+
+        let counters = MEASUREMENT.replace(outer).unwrap_or_default();
+        self.active.fetch_sub(1, Ordering::SeqCst);
+        let stats = AllocStats {
+            count: counters.count,
+            bytes: counters.bytes,
+            peak: counters.peak.max(0) as usize,
+        };
+        (res, stats)
+    }
+
+    fn record(&self, allocated: usize, freed: usize) {
+        if self.active.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        // Fails only while the thread is being torn down.
+        let _ = MEASUREMENT.try_with(|measurement| {
+            let Some(mut counters) = measurement.get() else {
+                return;
+            };
+            if allocated > 0 {
+                counters.count += 1;
+                counters.bytes += allocated;
+            }
+            counters.current += allocated as isize - freed as isize;
+            counters.peak = counters.peak.max(counters.current);
+            measurement.set(Some(counters));
+        });
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{}B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.2}KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.2}MiB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+pub fn print_alloc_stats(results: &[(RunResult, AllocStats)]) {
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>12}  {:>12}  Status",
+        "Day", "Part", "Allocs", "Bytes", "Peak"
+    );
+    for (r, stats) in results {
+        println!(
+            "{:>3}  {:>4}  {:>10}  {:>12}  {:>12}  {}",
+            r.day,
+            r.part,
+            stats.count,
+            format_bytes(stats.bytes),
+            format_bytes(stats.peak),
+            r.status
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::alloc::{GlobalAlloc, Layout};
+    use std::thread;
+
+    use super::{format_bytes, AllocStats, CountingAllocator};
+
+    #[test]
+    fn test_measure() {
+        let allocator = CountingAllocator::new();
+        let small = Layout::from_size_align(16, 8).unwrap();
+        let big = Layout::from_size_align(100, 8).unwrap();
+
+        // Not counted outside of measurement.
+        let before = unsafe { allocator.alloc(small) };
+
+        let (_, stats) = allocator.measure(|| unsafe {
+            let a = allocator.alloc(big);
+            let a = allocator.realloc(a, big, 200);
+            allocator.dealloc(a, Layout::from_size_align(200, 8).unwrap());
+            // Other threads are not counted.
+            thread::scope(|scope| {
+                scope.spawn(|| {
+                    let c = allocator.alloc(big);
+                    allocator.dealloc(c, big);
+                });
+            });
+            let b = allocator.alloc_zeroed(small);
+            allocator.dealloc(b, small);
+            allocator.dealloc(before, small);
+        });
+
+        assert_eq!(
+            stats,
+            AllocStats {
+                count: 3,
+                bytes: 316,
+                peak: 200,
+            }
+        );
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(100), "100B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00MiB");
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Context;
+
+use crate::bench::BenchResult;
+use crate::runner::format_duration;
+
+pub const BASELINE_FILE: &str = "baseline.txt";
+/// Allowed slowdown in percent before a run is reported as a regression.
+pub const DEFAULT_THRESHOLD: usize = 10;
+
+/// Median run time of every benchmarked solution keyed by day and part.
+pub type Baseline = BTreeMap<(u32, u32), Duration>;
+
+pub fn baseline_path<P: AsRef<Path>>(outputs_dir: P) -> PathBuf {
+    outputs_dir.as_ref().join(BASELINE_FILE)
+}
+
+/// Parses baseline file with `DD.P NANOS` lines. Empty lines and lines
+/// starting with `#` are skipped.
+pub fn parse_baseline(content: &str) -> anyhow::Result<Baseline> {
+    let mut baseline = Baseline::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let entry = || -> anyhow::Result<((u32, u32), Duration)> {
+            let (key, nanos) = line.split_once(' ').context("expected \"DD.P NANOS\"")?;
+            let (day, part) = key.split_once('.').context("expected \"DD.P\"")?;
+            Ok((
+                (day.parse()?, part.parse()?),
+                Duration::from_nanos(nanos.trim().parse()?),
+            ))
+        };
+        let (key, duration) = entry().with_context(|| format!("line {}: {:?}", i + 1, line))?;
+        baseline.insert(key, duration);
+    }
+    Ok(baseline)
+}
+
+/// Reads the baseline, missing file is treated as an empty one.
+pub fn read_baseline<P: AsRef<Path>>(path: P) -> anyhow::Result<Baseline> {
+    let path = path.as_ref();
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            parse_baseline(&content).with_context(|| format!("invalid {}", path.display()))
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Stores median times of `results` in the baseline file. Entries of
+/// solutions that were not benchmarked this time are kept.
+pub fn save_baseline<P: AsRef<Path>>(path: P, results: &[BenchResult]) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    let mut baseline = read_baseline(path)?;
+    for r in results {
+        baseline.insert((r.day, r.part), r.total.median);
+    }
+
+    let mut content = String::from("# day.part median_ns\n");
+    for ((day, part), duration) in &baseline {
+        content.push_str(&format!("{:02}.{} {}\n", day, part, duration.as_nanos()));
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u32,
+    pub part: u32,
+    /// `None` if the solution is not in the baseline yet.
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Change of the median time relative to the baseline in percent.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+        (baseline > 0.0).then(|| (self.current.as_secs_f64() / baseline - 1.0) * 100.0)
+    }
+}
+
+/// Compares median times with the baseline. A solution regressed if it is
+/// more than `threshold` percent slower.
+pub fn compare(results: &[BenchResult], baseline: &Baseline, threshold: usize) -> Vec<Comparison> {
+    results
+        .iter()
+        .map(|r| {
+            let baseline = baseline.get(&(r.day, r.part)).copied();
+            let current = r.total.median;
+            let regressed = match baseline {
+                Some(baseline) => {
+                    current.as_nanos() * 100 > baseline.as_nanos() * (100 + threshold as u128)
+                }
+                None => false,
+            };
+            Comparison {
+                day: r.day,
+                part: r.part,
+                baseline,
+                current,
+                regressed,
+            }
+        })
+        .collect()
+}
+
+pub fn print_comparisons(comparisons: &[Comparison], threshold: usize) {
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>10}  {:>8}  Status",
+        "Day", "Part", "Baseline", "Median", "Change"
+    );
+    for c in comparisons {
+        let status = match (c.baseline, c.regressed) {
+            (None, _) => "new",
+            (Some(_), true) => "SLOWER",
+            (Some(_), false) => "ok",
+        };
+        println!(
+            "{:>3}  {:>4}  {:>10}  {:>10}  {:>8}  {}",
+            c.day,
+            c.part,
+            c.baseline.map(format_duration).unwrap_or("-".to_string()),
+            format_duration(c.current),
+            c.change()
+                .map(|p| format!("{:+.1}%", p))
+                .unwrap_or("-".to_string()),
+            status
+        );
+    }
+
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    println!(
+        "{} solutions slower than the baseline by more than {}%",
+        regressed, threshold
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use std::env::temp_dir;
+    use std::fs;
+    use std::time::Duration;
+
+    use super::{compare, parse_baseline, read_baseline, save_baseline, Baseline};
+    use crate::bench::{BenchResult, Stats};
+
+    fn result(day: u32, part: u32, median_ms: u64) -> BenchResult {
+        let d = Duration::from_millis(median_ms);
+        BenchResult {
+            day,
+            part,
+            total: Stats {
+                min: d,
+                median: d,
+                mean: d,
+                p95: d,
+            },
+            phases: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_baseline() {
+        let baseline = parse_baseline("# comment\n01.1 1500\n\n12.2 7\n").unwrap();
+        assert_eq!(baseline.get(&(1, 1)), Some(&Duration::from_nanos(1500)));
+        assert_eq!(baseline.get(&(12, 2)), Some(&Duration::from_nanos(7)));
+
+        let err = parse_baseline("01.1 15\n01.2 x").unwrap_err();
+        assert!(format!("{:#}", err).contains("line 2"));
+        assert!(parse_baseline("011 15").is_err());
+    }
+
+    #[test]
+    fn test_save_baseline() {
+        let mut path = temp_dir();
+        path.push("aoc_2022_baseline_test");
+        let _ = fs::remove_dir_all(&path);
+        path.push("baseline.txt");
+
+        assert!(read_baseline(&path).unwrap().is_empty());
+
+        save_baseline(&path, &[result(1, 1, 3), result(2, 1, 5)]).unwrap();
+        save_baseline(&path, &[result(2, 1, 4)]).unwrap();
+
+        let baseline = read_baseline(&path).unwrap();
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline[&(1, 1)], Duration::from_millis(3));
+        assert_eq!(baseline[&(2, 1)], Duration::from_millis(4));
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline::from([
+            ((1, 1), Duration::from_millis(100)),
+            ((1, 2), Duration::from_millis(100)),
+        ]);
+        let results = [result(1, 1, 110), result(1, 2, 111), result(2, 1, 1)];
+
+        let comparisons = compare(&results, &baseline, 10);
+        let regressed: Vec<_> = comparisons.iter().map(|c| c.regressed).collect();
+        assert_eq!(regressed, vec![false, true, false]);
+        assert_eq!(comparisons[2].baseline, None);
+        assert!((comparisons[1].change().unwrap() - 11.0).abs() < 1e-9);
+    }
+}
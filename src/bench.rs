@@ -0,0 +1,149 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::runner::format_duration;
+use crate::solutions::Solution;
+
+pub const DEFAULT_WARMUP: usize = 3;
+pub const DEFAULT_ITERATIONS: usize = 20;
+
+/// Summary of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "expected at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let total: Duration = sorted.iter().sum();
+        // Nearest-rank percentile.
+        let p95_idx = ((sorted.len() * 95).div_ceil(100)).max(1) - 1;
+
+        Stats {
+            min: sorted[0],
+            median: sorted[sorted.len() / 2],
+            mean: total / sorted.len() as u32,
+            p95: sorted[p95_idx],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    pub day: u32,
+    pub part: u32,
+    pub total: Stats,
+    /// Parse and solve timings, for days with a separate parsing step.
+    pub phases: Option<(Stats, Stats)>,
+}
+
+/// Runs the solution `warmup` times without measuring and then `iterations`
+/// times measuring every run.
+pub fn bench_solution(
+    solution: &Solution,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> anyhow::Result<BenchResult> {
+    anyhow::ensure!(iterations > 0, "at least one iteration is required");
+
+    for _ in 0..warmup {
+        black_box(solution.run(black_box(input))?);
+    }
+
+    let mut total = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(solution.run(black_box(input))?);
+        total.push(start.elapsed());
+    }
+
+    let phases = match solution.phases {
+        Some(phases) => {
+            let mut parse = Vec::with_capacity(iterations);
+            let mut solve = Vec::with_capacity(iterations);
+            for _ in 0..iterations {
+                let (p, s) = phases(input)?;
+                parse.push(p);
+                solve.push(s);
+            }
+            Some((Stats::from_samples(&parse), Stats::from_samples(&solve)))
+        }
+        None => None,
+    };
+
+    Ok(BenchResult {
+        day: solution.day,
+        part: solution.part,
+        total: Stats::from_samples(&total),
+        phases,
+    })
+}
+
+pub fn print_bench(results: &[BenchResult]) {
+    println!(
+        "{:>3}  {:>4}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Part", "Phase", "Min", "Median", "Mean", "P95"
+    );
+
+    for r in results {
+        print_stats_row(r.day, r.part, "total", &r.total);
+        if let Some((parse, solve)) = &r.phases {
+            print_stats_row(r.day, r.part, "parse", parse);
+            print_stats_row(r.day, r.part, "solve", solve);
+        }
+    }
+}
+
+fn print_stats_row(day: u32, part: u32, phase: &str, stats: &Stats) {
+    println!(
+        "{:>3}  {:>4}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}",
+        day,
+        part,
+        phase,
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.mean),
+        format_duration(stats.p95)
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{bench_solution, Stats};
+    use crate::solutions::find;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(11));
+        assert_eq!(stats.mean, Duration::from_micros(10500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+    }
+
+    #[test]
+    fn test_bench_solution() {
+        let res = bench_solution(find(2022, 2, 1).unwrap(), "A Y\nB X\nC Z", 1, 5).expect("bench");
+        assert_eq!((res.day, res.part), (2, 1));
+        assert!(res.phases.is_none());
+
+        let input = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+        let res = bench_solution(find(2022, 14, 1).unwrap(), input, 0, 3).expect("bench");
+        assert!(res.phases.is_some());
+
+        assert!(bench_solution(find(2022, 2, 1).unwrap(), "A Y", 0, 0).is_err());
+    }
+}
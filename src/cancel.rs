@@ -0,0 +1,60 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+thread_local! {
+    static TOKEN: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// Returned by [`check`] once the running solution was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Runs `f` on the current thread with `token` as its cancellation flag.
+pub fn with_token<R>(token: Arc<AtomicBool>, f: impl FnOnce() -> R) -> R {
+    let previous = TOKEN.with(|t| t.replace(Some(token)));
+    let res = f();
+    TOKEN.with(|t| *t.borrow_mut() = previous);
+    res
+}
+
+/// Cooperative cancellation point for long running loops. Fails if the
+/// solution running on this thread has been cancelled, for example after
+/// exceeding its timeout. Never fails outside of [`with_token`].
+pub fn check() -> Result<(), Cancelled> {
+    TOKEN.with(|t| match &*t.borrow() {
+        Some(token) if token.load(Ordering::Relaxed) => Err(Cancelled),
+        _ => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use super::{check, with_token, Cancelled};
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(), Ok(()));
+
+        let token = Arc::new(AtomicBool::new(false));
+        with_token(token.clone(), || {
+            assert_eq!(check(), Ok(()));
+            token.store(true, Ordering::Relaxed);
+            assert_eq!(check(), Err(Cancelled));
+        });
+
+        assert_eq!(check(), Ok(()));
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::input::InputSource;
+use crate::runner::{self, RunResult, Status};
+use crate::solutions::Solution;
+
+/// Whether the optimized and the naive implementation agree on an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Agreement {
+    Same,
+    Differ {
+        fast: String,
+        naive: String,
+    },
+    /// One of the implementations did not produce an answer.
+    Error(String),
+}
+
+impl fmt::Display for Agreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Agreement::Same => write!(f, "same"),
+            Agreement::Differ { fast, naive } => {
+                write!(f, "DIFFER fast: {}, naive: {}", fast, naive)
+            }
+            Agreement::Error(err) => write!(f, "error: {}", err),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CrossCheck {
+    pub fast: RunResult,
+    pub naive: RunResult,
+    pub agreement: Agreement,
+}
+
+/// Runs both implementations of the solution on the input, `None` if the
+/// solution has no naive implementation.
+pub fn crosscheck_input(
+    solution: &Solution,
+    input: &str,
+    timeout: Option<Duration>,
+) -> Option<CrossCheck> {
+    let naive = Solution {
+        run: solution.naive?,
+        naive: None,
+        phases: None,
+        ..*solution
+    };
+
+    let fast = runner::run_with_timeout(solution, input, timeout);
+    let naive = runner::run_with_timeout(&naive, input, timeout);
+    let agreement = compare(&fast, &naive);
+    Some(CrossCheck {
+        fast,
+        naive,
+        agreement,
+    })
+}
+
+fn compare(fast: &RunResult, naive: &RunResult) -> Agreement {
+    match (&fast.status, &naive.status, &fast.answer, &naive.answer) {
+        (Status::Ok, Status::Ok, Some(a), Some(b)) if a == b => Agreement::Same,
+        (Status::Ok, Status::Ok, Some(a), Some(b)) => Agreement::Differ {
+            fast: a.clone(),
+            naive: b.clone(),
+        },
+        (Status::Ok, status, _, _) => Agreement::Error(format!("naive {}", status)),
+        (status, _, _, _) => Agreement::Error(format!("fast {}", status)),
+    }
+}
+
+/// Cross-checks the solutions that have a naive implementation on their
+/// inputs, others are skipped.
+pub fn crosscheck(
+    solutions: &[&Solution],
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> Vec<CrossCheck> {
+    let solutions: Vec<&Solution> = solutions
+        .iter()
+        .copied()
+        .filter(|s| s.naive.is_some())
+        .collect();
+    let inputs = runner::load_inputs(&solutions, source);
+
+    solutions
+        .iter()
+        .filter_map(|solution| match &inputs[&solution.day] {
+            Ok(input) => crosscheck_input(solution, input, timeout),
+            Err(err) => {
+                let failed = runner::load_failure(solution, err);
+                Some(CrossCheck {
+                    fast: failed.clone(),
+                    naive: failed,
+                    agreement: Agreement::Error(err.clone()),
+                })
+            }
+        })
+        .collect()
+}
+
+pub fn print_crosschecks(checks: &[CrossCheck]) {
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>10}  Result",
+        "Day", "Part", "Fast", "Naive"
+    );
+    for c in checks {
+        println!(
+            "{:>3}  {:>4}  {:>10}  {:>10}  {}",
+            c.fast.day,
+            c.fast.part,
+            runner::format_duration(c.fast.duration),
+            runner::format_duration(c.naive.duration),
+            c.agreement
+        );
+    }
+
+    let count = |f: fn(&Agreement) -> bool| checks.iter().filter(|c| f(&c.agreement)).count();
+    println!(
+        "{} same, {} differ, {} errors",
+        count(|a| *a == Agreement::Same),
+        count(|a| matches!(a, Agreement::Differ { .. })),
+        count(|a| matches!(a, Agreement::Error(_))),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{crosscheck, crosscheck_input, Agreement};
+    use crate::input::InputSource;
+    use crate::solutions::{all, find, Solution};
+
+    #[test]
+    fn test_examples() {
+        let mut checked = 0;
+        for solution in all().filter(|s| s.naive.is_some()) {
+            for example in solution.examples.iter() {
+                let check = crosscheck_input(solution, example.input, None).unwrap();
+                assert_eq!(
+                    check.agreement,
+                    Agreement::Same,
+                    "day {} part {} example {}",
+                    solution.day,
+                    solution.part,
+                    example.name
+                );
+                checked += 1;
+            }
+        }
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn test_crosscheck() {
+        let day_2 = find(2022, 2, 1).unwrap();
+        assert!(crosscheck_input(day_2, "A Y", None).is_none());
+
+        let wrong = Solution {
+            naive: Some(|_| Ok(Box::new(7))),
+            ..*day_2
+        };
+        let check = crosscheck_input(&wrong, "A Y", None).unwrap();
+        assert_eq!(
+            check.agreement,
+            Agreement::Differ {
+                fast: "8".to_string(),
+                naive: "7".to_string()
+            }
+        );
+
+        let failing = Solution {
+            naive: Some(|_| anyhow::bail!("boom")),
+            ..*day_2
+        };
+        let check = crosscheck_input(&failing, "A Y", None).unwrap();
+        assert_eq!(
+            check.agreement,
+            Agreement::Error("naive error: boom".into())
+        );
+
+        // Solutions without naive implementation are skipped, missing input
+        // of the others is reported.
+        let missing = InputSource::Dir(PathBuf::from("does/not/exist"));
+        let checks = crosscheck(&[day_2, find(2022, 8, 1).unwrap()], &missing, None);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].fast.day, 8);
+        assert!(matches!(checks[0].agreement, Agreement::Error(_)));
+    }
+}
@@ -0,0 +1,221 @@
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{env, fs};
+
+use anyhow::Context;
+
+use crate::solutions::DEFAULT_YEAR;
+use crate::util::read::{self, ReadError};
+use crate::util::{input_warnings, normalize_input};
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+static WARN_WHITESPACE: AtomicBool = AtomicBool::new(false);
+
+/// Enables printing warnings about odd whitespace in loaded inputs to stderr.
+pub fn set_warn_whitespace(enabled: bool) {
+    WARN_WHITESPACE.store(enabled, Ordering::Relaxed);
+}
+
+/// Where the puzzle input for a run comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `<inputs dir>/NN.txt` for the requested day.
+    Dir(PathBuf),
+    /// Explicit file path.
+    File(PathBuf),
+    /// Read everything from stdin (`-` on the command line).
+    Stdin,
+}
+
+impl InputSource {
+    /// Builds the source from the optional `INPUT` argument and the optional
+    /// inputs directory override. Falls back to `$AOC_INPUTS_DIR` and then to
+    /// `inputs/`.
+    pub fn from_args(input: Option<&str>, inputs_dir: Option<&str>) -> Self {
+        match input {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::Dir(
+                inputs_dir
+                    .map(PathBuf::from)
+                    .or_else(|| env::var_os(INPUTS_DIR_ENV).map(PathBuf::from))
+                    .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUTS_DIR)),
+            ),
+        }
+    }
+
+    /// Source reading inputs of the year, see [`year_dir`]. Explicit file and
+    /// stdin sources are not affected.
+    pub fn with_year(&self, year: u32) -> Self {
+        match self {
+            InputSource::Dir(dir) => InputSource::Dir(year_dir(dir, year)),
+            source => source.clone(),
+        }
+    }
+
+    /// Source reading inputs of the profile from `<inputs dir>/<profile>`.
+    /// Explicit file and stdin sources are not affected.
+    pub fn with_profile(&self, profile: &str) -> Self {
+        match self {
+            InputSource::Dir(dir) => InputSource::Dir(dir.join(profile)),
+            source => source.clone(),
+        }
+    }
+
+    /// Loads input of the day normalized with [`normalize_input`].
+    pub fn load(&self, day: u32) -> anyhow::Result<String> {
+        let input = normalize_input(&self.load_raw(day)?);
+        anyhow::ensure!(!input.is_empty(), "input for day {} is empty", day);
+        if WARN_WHITESPACE.load(Ordering::Relaxed) {
+            for warning in input_warnings(&input) {
+                eprintln!("Warning: day {} input {}", day, warning);
+            }
+        }
+        Ok(input)
+    }
+
+    fn load_raw(&self, day: u32) -> anyhow::Result<String> {
+        match self {
+            InputSource::Dir(dir) => read_input_file(input_path(dir, day)),
+            InputSource::File(path) => read_input_file(path),
+            InputSource::Stdin => {
+                read_all(io::stdin().lock()).context("failed to read input from stdin")
+            }
+        }
+    }
+}
+
+/// Directory with inputs or outputs of the year. Files of the default year are
+/// kept directly in `dir`, other years use `dir/<year>`.
+pub fn year_dir<P: AsRef<Path>>(dir: P, year: u32) -> PathBuf {
+    match year {
+        DEFAULT_YEAR => dir.as_ref().to_path_buf(),
+        year => dir.as_ref().join(year.to_string()),
+    }
+}
+
+/// Names of profiles in the inputs directory, i.e. its subdirectories that
+/// are not years, in order. Each profile is a team member with their own
+/// `<profile>/NN.txt` inputs.
+pub fn list_profiles<P: AsRef<Path>>(inputs_dir: P) -> anyhow::Result<Vec<String>> {
+    let inputs_dir = inputs_dir.as_ref();
+    let entries = fs::read_dir(inputs_dir)
+        .with_context(|| format!("failed to list profiles in {}", inputs_dir.display()))?;
+
+    let mut profiles = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if entry.file_type()?.is_dir() && !name.bytes().all(|b| b.is_ascii_digit()) {
+            profiles.push(name);
+        }
+    }
+    profiles.sort();
+    Ok(profiles)
+}
+
+pub fn input_path<P: AsRef<Path>>(dir: P, day: u32) -> PathBuf {
+    dir.as_ref().join(format!("{:02}.txt", day))
+}
+
+fn read_input_file<P: AsRef<Path>>(path: P) -> anyhow::Result<String> {
+    let path = path.as_ref();
+    read::open(path)
+        .and_then(read_all)
+        .with_context(|| format!("failed to read input file {}", path.display()))
+}
+
+/// Reads all lines joined by `\n`, leaving the rest to [`normalize_input`].
+fn read_all<R: BufRead>(reader: R) -> Result<String, ReadError> {
+    let lines = read::lines(reader)
+        .map(|line| line.map(|(_, text)| text))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use std::env::temp_dir;
+    use std::fs;
+
+    use super::{input_path, list_profiles, year_dir, InputSource};
+    use crate::util::temp_file_with_content;
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(InputSource::from_args(Some("-"), None), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_args(Some("my.txt"), Some("other")),
+            InputSource::File(PathBuf::from("my.txt"))
+        );
+        assert_eq!(
+            InputSource::from_args(None, Some("alice")),
+            InputSource::Dir(PathBuf::from("alice"))
+        );
+    }
+
+    #[test]
+    fn test_profiles() {
+        let mut dir = temp_dir();
+        dir.push("aoc_2022_profiles");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("bob")).unwrap();
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        fs::create_dir_all(dir.join("2023")).unwrap();
+        fs::write(dir.join("01.txt"), "1").unwrap();
+        fs::write(dir.join("alice").join("01.txt"), "2").unwrap();
+
+        assert_eq!(list_profiles(&dir).unwrap(), vec!["alice", "bob"]);
+        assert!(list_profiles(dir.join("carol")).is_err());
+
+        let source = InputSource::Dir(dir.clone());
+        assert_eq!(source.load(1).unwrap(), "1");
+        assert_eq!(source.with_profile("alice").load(1).unwrap(), "2");
+        assert!(source.with_profile("bob").load(1).is_err());
+        assert_eq!(InputSource::Stdin.with_profile("bob"), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_year_dir() {
+        assert_eq!(year_dir("inputs", 2022), PathBuf::from("inputs"));
+        assert_eq!(year_dir("inputs", 2023), PathBuf::from("inputs/2023"));
+        assert_eq!(
+            InputSource::Dir(PathBuf::from("in")).with_year(2021),
+            InputSource::Dir(PathBuf::from("in/2021"))
+        );
+        assert_eq!(
+            InputSource::File(PathBuf::from("a.txt")).with_year(2021),
+            InputSource::File(PathBuf::from("a.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(input_path("inputs", 3), PathBuf::from("inputs/03.txt"));
+        assert_eq!(input_path("inputs", 12), PathBuf::from("inputs/12.txt"));
+    }
+
+    #[test]
+    fn test_load() {
+        let path = temp_file_with_content("aoc_2022_input_test.txt", "1000\n2000");
+        let source = InputSource::File(path);
+        assert_eq!(source.load(1).expect("failed to load"), "1000\n2000");
+
+        let path = temp_file_with_content("aoc_2022_input_crlf.txt", "\u{feff}1000\r\n2000\r\n");
+        let source = InputSource::File(path);
+        assert_eq!(source.load(1).expect("failed to load"), "1000\n2000");
+
+        let missing = InputSource::Dir(PathBuf::from("does/not/exist"));
+        let err = missing.load(7).expect_err("expected missing file error");
+        assert!(format!("{:#}", err).contains("07.txt"));
+
+        let path = temp_file_with_content("aoc_2022_input_empty.txt", "\r\n");
+        let err = InputSource::File(path).load(3).unwrap_err();
+        assert_eq!(err.to_string(), "input for day 3 is empty");
+    }
+}
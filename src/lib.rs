@@ -0,0 +1,30 @@
+//! Advent of Code solutions, grouped by year in `yYYYY` modules.
+//!
+//! Every `dayNN` module exposes its parsed model types and `task_1`/`task_2`
+//! functions that take the raw puzzle input. [`solutions::YEARS`] lists all
+//! of them and is what the binary runs.
+//!
+//! ```
+//! use advent_of_code_2022::y2022::day13::{parse_packet, Packet};
+//!
+//! assert!(parse_packet("[1,[2]]")? < parse_packet("[[3]]")?);
+//! assert_eq!(parse_packet("[7]")?, Packet::List(vec![Packet::Literal(7)]));
+//! # Ok::<(), advent_of_code_2022::parse::ParseError>(())
+//! ```
+
+pub mod alloc_stats;
+pub mod baseline;
+pub mod bench;
+pub mod cancel;
+pub mod crosscheck;
+pub mod input;
+pub mod parse;
+pub mod runner;
+pub mod scaffold;
+pub mod solutions;
+#[cfg(test)]
+pub(crate) mod testing;
+pub mod trace;
+pub mod util;
+pub mod verify;
+pub mod y2022;
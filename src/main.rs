@@ -1,305 +1,717 @@
-use std::{env, fmt::Display, process};
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod util;
-
-pub fn run_task<T, O>(func: T, day: &str, task: &str, input: &str)
-where
-    T: Fn(&str) -> anyhow::Result<O>,
-    O: Display,
-{
-    println!("Running Day {} task {}", day, task);
-
-    let out = func(input).expect("failed to run");
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, process};
+
+use advent_of_code_2022::alloc_stats::{self, CountingAllocator};
+use advent_of_code_2022::input::{self, InputSource, INPUTS_DIR_ENV};
+use advent_of_code_2022::runner::{self, OutputFormat};
+use advent_of_code_2022::solutions::{self, Solution};
+use advent_of_code_2022::trace::{self, Level};
+use advent_of_code_2022::{baseline, bench, crosscheck, scaffold, verify};
+
+// Counts allocations for --alloc-stats, see `CountingAllocator` for its cost
+// when not measuring.
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator::new();
+
+fn run_task(solution: &Solution, source: &InputSource) {
+    println!("Running Day {} task {}", solution.day, solution.part);
+
+    let input = match source.load(solution.day) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed to load input for day {}: {:#}", solution.day, err);
+            process::exit(1);
+        }
+    };
+
+    let out = match solution.run(&input) {
+        Ok(out) => out,
+        Err(err) => {
+            eprintln!(
+                "Day {} task {} failed: {:#}",
+                solution.day, solution.part, err
+            );
+            process::exit(1);
+        }
+    };
 
     println!("Output: {}", out);
 }
 
-fn main() {
-    println!("Running Advent of code 2022!");
+fn run_example(solution: &Solution, name: &str, format: OutputFormat) {
+    let Some(example) = solution.example(name) else {
+        let names: Vec<&str> = solution.examples().map(|e| e.name).collect();
+        eprintln!(
+            "Day {} task {} has no example {}, available: {}",
+            solution.day,
+            solution.part,
+            name,
+            names.join(", ")
+        );
+        process::exit(1)
+    };
 
-    let args: Vec<String> = env::args().collect();
+    let res = runner::run_solution(solution, example.input);
+    let expected = solution.expected(example).unwrap_or_default();
+    let verdict = match &res.answer {
+        Some(answer) => verify::check_answer(Some(expected), answer),
+        None => verify::Verdict::Error(res.status.to_string()),
+    };
 
-    if args.len() < 3 {
-        println!("Invalid arguments. Provide [DAY] [TASK] as an arguments.");
+    match format {
+        OutputFormat::Text => {
+            println!(
+                "Running Day {} task {} on example {}",
+                solution.day, solution.part, example.name
+            );
+            println!("Output: {}", res.answer.as_deref().unwrap_or("-"));
+            println!("Expected: {}", expected);
+            println!("Result: {}", verdict);
+        }
+        OutputFormat::Json => println!("{}", res.to_json()),
+    }
+
+    if verdict != verify::Verdict::Pass {
         process::exit(1);
     }
+}
 
-    let args: [&str; 2] = [&args[1], &args[2]];
+fn list_solutions(year: u32) {
+    println!("Available days of {}:", year);
+    for day in solutions::days(year) {
+        let parts: Vec<String> = solutions::all()
+            .filter(|s| s.year == year && s.day == day)
+            .map(|s| s.part.to_string())
+            .collect();
+        let examples: Vec<&str> = solutions::all()
+            .find(|s| s.year == year && s.day == day)
+            .map(|s| s.examples.iter().map(|e| e.name).collect())
+            .unwrap_or_default();
+        println!(
+            "  Day {:>2}: tasks {}, examples: {}",
+            day,
+            parts.join(", "),
+            if examples.is_empty() {
+                "-".to_string()
+            } else {
+                examples.join(", ")
+            }
+        );
+    }
+}
 
-    match args {
-        [day @ "1", task @ "1"] => {
-            run_task(day01::day_1_1, day, task, include_str!("../inputs/01.txt"))
-        }
-        [day @ "1", task @ "2"] => {
-            run_task(day01::day_1_2, day, task, include_str!("../inputs/01.txt"))
+fn run_selection(
+    solutions: &[&Solution],
+    source: &InputSource,
+    format: OutputFormat,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> bool {
+    let results = runner::run_parallel(solutions, source, jobs, timeout);
+    match format {
+        OutputFormat::Text => runner::print_table(&results),
+        OutputFormat::Json => {
+            for res in &results {
+                println!("{}", res.to_json());
+            }
         }
-        [day @ "2", task @ "1"] => {
-            run_task(day02::day_2_1, day, task, include_str!("../inputs/02.txt"))
-        }
-        [day @ "2", task @ "2"] => {
-            run_task(day02::day_2_2, day, task, include_str!("../inputs/02.txt"))
-        }
-        [day @ "3", task @ "1"] => {
-            run_task(day03::task_1, day, task, include_str!("../inputs/03.txt"))
-        }
-        [day @ "3", task @ "2"] => {
-            run_task(day03::task_2, day, task, include_str!("../inputs/03.txt"))
-        }
-        [day @ "4", task @ "1"] => {
-            run_task(day04::task_1, day, task, include_str!("../inputs/04.txt"))
-        }
-        [day @ "4", task @ "2"] => {
-            run_task(day04::task_2, day, task, include_str!("../inputs/04.txt"))
-        }
-        [day @ "5", task @ "1"] => {
-            run_task(day05::task_1, day, task, include_str!("../inputs/05.txt"))
+    }
+
+    results.iter().all(|r| r.status == runner::Status::Ok)
+}
+
+fn run_alloc_stats(solutions: &[&Solution], source: &InputSource) -> bool {
+    let inputs = runner::load_inputs(solutions, source);
+
+    let results: Vec<_> = solutions
+        .iter()
+        .map(|solution| match &inputs[&solution.day] {
+            Ok(input) => ALLOC.measure(|| runner::run_solution(solution, input)),
+            Err(err) => (runner::load_failure(solution, err), Default::default()),
+        })
+        .collect();
+
+    alloc_stats::print_alloc_stats(&results);
+
+    results.iter().all(|(r, _)| r.status == runner::Status::Ok)
+}
+
+/// What to do with timing baselines after benchmarking.
+struct BaselineOptions {
+    /// Set by `--baseline`, otherwise the baseline of the profile is used.
+    path: Option<PathBuf>,
+    save: bool,
+    compare: bool,
+    threshold: usize,
+}
+
+impl BaselineOptions {
+    fn path(&self, profile: &Profile) -> PathBuf {
+        match &self.path {
+            Some(path) => path.clone(),
+            None => baseline::baseline_path(&profile.outputs_dir),
         }
-        [day @ "5", task @ "2"] => {
-            run_task(day05::task_2, day, task, include_str!("../inputs/05.txt"))
+    }
+}
+
+fn run_bench(
+    solutions: &[&Solution],
+    profile: &Profile,
+    warmup: usize,
+    iterations: usize,
+    baseline_opts: &BaselineOptions,
+) {
+    let baseline_path = baseline_opts.path(profile);
+    println!(
+        "Benchmarking {} solutions ({} warmup runs, {} iterations)",
+        solutions.len(),
+        warmup,
+        iterations
+    );
+
+    let mut results = Vec::new();
+    let mut failed = false;
+    for solution in solutions {
+        let res = profile
+            .source
+            .load(solution.day)
+            .and_then(|input| bench::bench_solution(solution, &input, warmup, iterations));
+        match res {
+            Ok(res) => results.push(res),
+            Err(err) => {
+                failed = true;
+                eprintln!(
+                    "Day {} task {} failed: {:#}",
+                    solution.day, solution.part, err
+                );
+            }
         }
-        [day @ "6", task @ "1"] => {
-            run_task(day06::task_1, day, task, include_str!("../inputs/06.txt"))
+    }
+
+    bench::print_bench(&results);
+
+    if baseline_opts.compare {
+        match baseline::read_baseline(&baseline_path) {
+            Ok(saved) => {
+                let comparisons = baseline::compare(&results, &saved, baseline_opts.threshold);
+                println!();
+                baseline::print_comparisons(&comparisons, baseline_opts.threshold);
+                failed |= comparisons.iter().any(|c| c.regressed);
+            }
+            Err(err) => {
+                failed = true;
+                eprintln!("Failed to read baseline: {:#}", err);
+            }
         }
-        [day @ "6", task @ "2"] => {
-            run_task(day06::task_2, day, task, include_str!("../inputs/06.txt"))
+    }
+
+    if baseline_opts.save {
+        match baseline::save_baseline(&baseline_path, &results) {
+            Ok(()) => println!("Baseline saved to {}", baseline_path.display()),
+            Err(err) => {
+                failed = true;
+                eprintln!("Failed to save baseline: {:#}", err);
+            }
         }
-        [day @ "7", task @ "1"] => {
-            run_task(day07::task_1, day, task, include_str!("../inputs/07.txt"))
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_verify(
+    solutions: &[&Solution],
+    source: &InputSource,
+    outputs_dir: &Path,
+    record: bool,
+) -> bool {
+    let verifications = verify::verify(solutions, source, outputs_dir, record);
+    verify::print_verifications(&verifications);
+
+    !verifications.iter().any(|v| {
+        matches!(
+            v.verdict,
+            verify::Verdict::Fail { .. } | verify::Verdict::Error(_)
+        )
+    })
+}
+
+fn run_crosscheck(
+    solutions: &[&Solution],
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> bool {
+    let checks = crosscheck::crosscheck(solutions, source, timeout);
+    crosscheck::print_crosschecks(&checks);
+
+    checks
+        .iter()
+        .all(|c| c.agreement == crosscheck::Agreement::Same)
+}
+
+/// Creates and registers a new day of the year in `src/`, with empty input
+/// and answer files.
+fn new_day(day: &str, year: u32, profile: &Profile) {
+    let created = day.parse().map_err(anyhow::Error::from).and_then(|day| {
+        let InputSource::Dir(inputs_dir) = &profile.source else {
+            anyhow::bail!("new-day needs an inputs directory");
+        };
+        scaffold::new_day(
+            Path::new("src"),
+            inputs_dir,
+            &profile.outputs_dir,
+            year,
+            day,
+        )
+    });
+    match created {
+        Ok(created) => {
+            for path in created {
+                println!("Created {}", path.display());
+            }
         }
-        [day @ "7", task @ "2"] => {
-            run_task(day07::task_2, day, task, include_str!("../inputs/07.txt"))
+        Err(err) => {
+            eprintln!("Failed to create day {}: {:#}", day, err);
+            process::exit(1)
         }
-        [day @ "8", task @ "1"] => {
-            run_task(day08::task_1, day, task, include_str!("../inputs/08.txt"))
+    }
+}
+
+/// Inputs and answers of a single team member.
+struct Profile {
+    name: Option<String>,
+    source: InputSource,
+    outputs_dir: PathBuf,
+}
+
+/// Resolves `--profile NAME` or `--all-profiles` into the profiles to run.
+/// Without them the plain inputs and outputs directories are used.
+fn select_profiles(
+    source: &InputSource,
+    outputs_dir: &Path,
+    profile: Option<String>,
+    all_profiles: bool,
+) -> Vec<Profile> {
+    let names = match (profile, all_profiles, source) {
+        (Some(_), true, _) => {
+            eprintln!("Use either --profile or --all-profiles");
+            process::exit(1)
         }
-        [day @ "8", task @ "2"] => {
-            run_task(day08::task_2, day, task, include_str!("../inputs/08.txt"))
+        (_, true, InputSource::Dir(dir)) => match input::list_profiles(dir) {
+            Ok(names) if !names.is_empty() => names.into_iter().map(Some).collect(),
+            Ok(_) => {
+                eprintln!("No profiles found in {}", dir.display());
+                process::exit(1)
+            }
+            Err(err) => {
+                eprintln!("{:#}", err);
+                process::exit(1)
+            }
+        },
+        (_, true, _) => {
+            eprintln!("--all-profiles cannot be used with an explicit INPUT");
+            process::exit(1)
         }
-        [day @ "9", task @ "1"] => {
-            run_task(day09::task_1, day, task, include_str!("../inputs/09.txt"))
+        (profile, false, _) => vec![profile],
+    };
+
+    names
+        .into_iter()
+        .map(|name| Profile {
+            source: match &name {
+                Some(name) => source.with_profile(name),
+                None => source.clone(),
+            },
+            outputs_dir: match &name {
+                Some(name) => outputs_dir.join(name),
+                None => outputs_dir.to_path_buf(),
+            },
+            name,
+        })
+        .collect()
+}
+
+/// Exits for subcommands that only print text tables.
+fn require_text_format(format: OutputFormat, command: &str) {
+    if format == OutputFormat::Json {
+        eprintln!("--format json is not supported by {}", command);
+        process::exit(1)
+    }
+}
+
+/// Runs `f` for every profile and exits with error if any of them failed.
+fn for_each_profile(
+    profiles: &[Profile],
+    format: OutputFormat,
+    mut f: impl FnMut(&Profile) -> bool,
+) {
+    let mut ok = true;
+    for profile in profiles {
+        if let (Some(name), OutputFormat::Text) = (&profile.name, format) {
+            println!("Profile: {}", name);
         }
-        [day @ "9", task @ "2"] => {
-            run_task(day09::task_2, day, task, include_str!("../inputs/09.txt"))
+        ok &= f(profile);
+    }
+    if !ok {
+        process::exit(1);
+    }
+}
+
+fn print_usage() {
+    eprintln!("Invalid arguments. Provide [DAY] [TASK] [INPUT] as an arguments.");
+    eprintln!("Provide only [DAYS] (e.g. all, 7 or 3..=9) to run every task of these days.");
+    eprintln!("INPUT is a file path or - for stdin, by default inputs/DAY.txt is used.");
+    eprintln!(
+        "Use --inputs DIR (or {}) to change the inputs directory.",
+        INPUTS_DIR_ENV
+    );
+    eprintln!(
+        "Use `verify [DAYS | DAY TASK [INPUT]] [--record] [--outputs DIR]` to check answers."
+    );
+    eprintln!("Use `crosscheck [DAYS | DAY TASK [INPUT]]` to compare fast tasks with naive ones.");
+    eprintln!("Use `run DAY TASK --example NAME` to run on example from the puzzle.");
+    eprintln!("Use --format json to print one JSON record per run, not supported by");
+    eprintln!("verify, crosscheck, bench and --alloc-stats.");
+    eprintln!("Use --profile NAME to use inputs/NAME/ and outputs/NAME/ of a team member,");
+    eprintln!("or --all-profiles to run and verify every profile found in inputs/.");
+    eprintln!("Use --jobs N to run [DAYS] on N threads.");
+    eprintln!("Use --timeout SECONDS to stop waiting for tasks that run too long.");
+    eprintln!("Use --warn-input to report odd whitespace in inputs.");
+    eprintln!("Use -v or -vv to print intermediate state of solvers to stderr.");
+    eprintln!("Use --alloc-stats to report allocations and peak memory of each task,");
+    eprintln!("tasks are then run one at a time on the main thread.");
+    eprintln!("Use `list` to show available days.");
+    eprintln!("Use `new-day DAY` to add a module for the day with empty input and answer files.");
+    eprintln!(
+        "Use --year YEAR to select the event year, {} by default. Inputs and outputs",
+        solutions::DEFAULT_YEAR
+    );
+    eprintln!("of other years are read from YEAR/ subdirectories.");
+    eprintln!(
+        "Use `bench [DAYS | DAY TASK [INPUT]] [--warmup N] [--iterations N]` to benchmark tasks."
+    );
+    eprintln!(
+        "Add --save-baseline to store median times in OUTPUTS/{} (or --baseline FILE),",
+        baseline::BASELINE_FILE
+    );
+    eprintln!("and --compare [--threshold PERCENT] to report tasks slower than the baseline.");
+}
+
+/// Resolves positional `[DAYS]` or `[DAY] [TASK] [INPUT]` arguments into
+/// solutions and the source of their input.
+fn select_solutions(
+    args: &[String],
+    inputs_dir: Option<&str>,
+    year: u32,
+) -> (Vec<&'static Solution>, InputSource) {
+    match args {
+        [days] => {
+            let days = match runner::parse_days(days) {
+                Ok(days) => days,
+                Err(err) => {
+                    eprintln!("Invalid days selection {}: {:#}", days, err);
+                    process::exit(1);
+                }
+            };
+            (
+                runner::select(year, &days),
+                InputSource::from_args(None, inputs_dir).with_year(year),
+            )
         }
-        [day @ "10", task @ "1"] => {
-            run_task(day10::task_1, day, task, include_str!("../inputs/10.txt"))
+        [day, task] | [day, task, _] => {
+            let solution = match (day.parse(), task.parse()) {
+                (Ok(day), Ok(task)) => solutions::find(year, day, task),
+                _ => None,
+            };
+            let Some(solution) = solution else {
+                eprintln!("Invalid arguments, day: {}, task: {}", day, task);
+                process::exit(1)
+            };
+            let source =
+                InputSource::from_args(args.get(2).map(|s| s.as_str()), inputs_dir).with_year(year);
+            (vec![solution], source)
         }
-        [day @ "10", task @ "2"] => {
-            run_task(day10::task_2, day, task, include_str!("../inputs/10.txt"))
+        _ => {
+            print_usage();
+            process::exit(1);
         }
-        [day @ "11", task @ "1"] => {
-            run_task(day11::task_1, day, task, include_str!("../inputs/11.txt"))
+    }
+}
+
+/// Removes `flag VALUE` from args and returns the value.
+fn take_flag_value(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    let idx = args.iter().position(|a| a == flag)?;
+    if idx + 1 >= args.len() {
+        eprintln!("Missing value for {}", flag);
+        process::exit(1);
+    }
+    let value = args.remove(idx + 1);
+    args.remove(idx);
+    Some(value)
+}
+
+/// Removes `flag` from args and returns whether it was present.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    match args.iter().position(|a| a == flag) {
+        Some(idx) => {
+            args.remove(idx);
+            true
         }
-        [day @ "11", task @ "2"] => {
-            run_task(day11::task_2, day, task, include_str!("../inputs/11.txt"))
+        None => false,
+    }
+}
+
+/// Counts `-v` flags, `-vv` counts as two.
+fn take_verbosity(args: &mut Vec<String>) -> usize {
+    let mut verbosity = 0;
+    args.retain(|arg| match arg.as_str() {
+        "-v" => {
+            verbosity += 1;
+            false
         }
-        [day @ "12", task @ "1"] => {
-            run_task(day12::task_1, day, task, include_str!("../inputs/12.txt"))
+        "-vv" => {
+            verbosity += 2;
+            false
         }
-        [day @ "12", task @ "2"] => {
-            run_task(day12::task_2, day, task, include_str!("../inputs/12.txt"))
+        _ => true,
+    });
+    verbosity
+}
+
+fn take_number_flag(args: &mut Vec<String>, flag: &str, default: usize) -> usize {
+    match take_flag_value(args, flag) {
+        Some(value) => value.parse().unwrap_or_else(|_| {
+            eprintln!("Invalid value for {}: {}", flag, value);
+            process::exit(1)
+        }),
+        None => default,
+    }
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    trace::set_level(Level::from_verbosity(take_verbosity(&mut args)));
+    let inputs_dir = take_flag_value(&mut args, "--inputs");
+    let year = match take_flag_value(&mut args, "--year").map(|y| y.parse()) {
+        Some(Ok(year)) if solutions::years().contains(&year) => year,
+        Some(_) => {
+            eprintln!(
+                "Invalid --year, available: {}",
+                solutions::years()
+                    .iter()
+                    .map(|y| y.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            process::exit(1)
         }
-        [day @ "13", task @ "1"] => {
-            run_task(day13::task_1, day, task, include_str!("../inputs/13.txt"))
+        None => solutions::DEFAULT_YEAR,
+    };
+    let outputs_dir = take_flag_value(&mut args, "--outputs")
+        .unwrap_or_else(|| verify::DEFAULT_OUTPUTS_DIR.to_string());
+    let outputs_dir = input::year_dir(&outputs_dir, year);
+    let record = take_flag(&mut args, "--record");
+    let alloc_stats = take_flag(&mut args, "--alloc-stats");
+    input::set_warn_whitespace(take_flag(&mut args, "--warn-input"));
+    let example = take_flag_value(&mut args, "--example");
+    let format = match take_flag_value(&mut args, "--format").map(|f| f.parse()) {
+        Some(Ok(format)) => format,
+        Some(Err(err)) => {
+            eprintln!("Invalid --format: {:#}", err);
+            process::exit(1)
         }
-        [day @ "13", task @ "2"] => {
-            run_task(day13::task_2, day, task, include_str!("../inputs/13.txt"))
+        None => OutputFormat::Text,
+    };
+
+    if format == OutputFormat::Text {
+        println!("Running Advent of code {}!", year);
+    }
+    let warmup = take_number_flag(&mut args, "--warmup", bench::DEFAULT_WARMUP);
+    let iterations = take_number_flag(&mut args, "--iterations", bench::DEFAULT_ITERATIONS);
+    let jobs = take_number_flag(&mut args, "--jobs", 1);
+    if alloc_stats && jobs > 1 {
+        eprintln!("--alloc-stats measures tasks one at a time and cannot be used with --jobs");
+        process::exit(1)
+    }
+    let timeout = take_flag_value(&mut args, "--timeout").map(|value| {
+        match value
+            .parse::<f64>()
+            .ok()
+            .and_then(|s| Duration::try_from_secs_f64(s).ok())
+        {
+            Some(timeout) => timeout,
+            None => {
+                eprintln!("Invalid value for --timeout: {}", value);
+                process::exit(1)
+            }
         }
-        [day @ "14", task @ "1"] => {
-            run_task(day14::task_1, day, task, include_str!("../inputs/14.txt"))
+    });
+    let baseline_opts = BaselineOptions {
+        path: take_flag_value(&mut args, "--baseline").map(PathBuf::from),
+        save: take_flag(&mut args, "--save-baseline"),
+        compare: take_flag(&mut args, "--compare"),
+        threshold: take_number_flag(&mut args, "--threshold", baseline::DEFAULT_THRESHOLD),
+    };
+
+    let profile = take_flag_value(&mut args, "--profile");
+    let all_profiles = take_flag(&mut args, "--all-profiles");
+
+    match args.first().map(|a| a.as_str()) {
+        Some("list") => list_solutions(year),
+        Some("new-day") => {
+            let [_, day] = args.as_slice() else {
+                print_usage();
+                process::exit(1)
+            };
+            let source = InputSource::from_args(None, inputs_dir.as_deref()).with_year(year);
+            let profiles = select_profiles(&source, &outputs_dir, profile, false);
+            new_day(day, year, &profiles[0]);
         }
-        [day @ "14", task @ "2"] => {
-            run_task(day14::task_2, day, task, include_str!("../inputs/14.txt"))
+        Some("bench") => {
+            require_text_format(format, "bench");
+            let (solutions, source) = select_solutions(&args[1..], inputs_dir.as_deref(), year);
+            if all_profiles {
+                eprintln!("--all-profiles is not supported by bench, use --profile");
+                process::exit(1)
+            }
+            let profiles = select_profiles(&source, &outputs_dir, profile, false);
+            run_bench(&solutions, &profiles[0], warmup, iterations, &baseline_opts);
         }
-        [day @ "15", task @ "1"] => {
-            run_task(day15::task_1, day, task, include_str!("../inputs/15.txt"))
+        Some("verify") => {
+            require_text_format(format, "verify");
+            let selection = match &args[1..] {
+                [] => vec!["all".to_string()],
+                rest => rest.to_vec(),
+            };
+            let (solutions, source) = select_solutions(&selection, inputs_dir.as_deref(), year);
+            let profiles = select_profiles(&source, &outputs_dir, profile, all_profiles);
+            for_each_profile(&profiles, format, |p| {
+                run_verify(&solutions, &p.source, &p.outputs_dir, record)
+            });
         }
-        [day @ "15", task @ "2"] => {
-            run_task(day15::task_2, day, task, include_str!("../inputs/15.txt"))
+        Some("crosscheck") => {
+            require_text_format(format, "crosscheck");
+            let selection = match &args[1..] {
+                [] => vec!["all".to_string()],
+                rest => rest.to_vec(),
+            };
+            let (solutions, source) = select_solutions(&selection, inputs_dir.as_deref(), year);
+            let profiles = select_profiles(&source, &outputs_dir, profile, all_profiles);
+            for_each_profile(&profiles, format, |p| {
+                run_crosscheck(&solutions, &p.source, timeout)
+            });
         }
-        [day, task] => {
-            println!("Invalid arguments, day: {}, task: {}", day, task);
-            process::exit(1)
+        command => {
+            let args = match command {
+                Some("run") => &args[1..],
+                _ => &args[..],
+            };
+            let (solutions, source) = select_solutions(args, inputs_dir.as_deref(), year);
+            let profiles = select_profiles(&source, &outputs_dir, profile, all_profiles);
+            let source = &profiles[0].source;
+            match (solutions.as_slice(), example) {
+                ([solution], Some(example)) if args.len() == 2 => {
+                    run_example(solution, &example, format)
+                }
+                (_, Some(_)) => {
+                    eprintln!("--example requires a single [DAY] [TASK]");
+                    process::exit(1)
+                }
+                (_, None) if alloc_stats => {
+                    require_text_format(format, "--alloc-stats");
+                    for_each_profile(&profiles, format, |p| {
+                        run_alloc_stats(&solutions, &p.source)
+                    })
+                }
+                ([solution], None)
+                    if args.len() > 1
+                        && format == OutputFormat::Text
+                        && timeout.is_none()
+                        && profiles.len() == 1 =>
+                {
+                    run_task(solution, source)
+                }
+                (_, None) => for_each_profile(&profiles, format, |p| {
+                    run_selection(&solutions, &p.source, format, jobs, timeout)
+                }),
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::day01::{day_1_1, day_1_2};
-    use crate::day02::{day_2_1, day_2_2};
-    use crate::day03;
-    use crate::day04;
-    use crate::day05;
-    use crate::day06;
-    use crate::day07;
-    use crate::day08;
-    use crate::day09;
-    use crate::day10;
-    use crate::day11;
-    use crate::day12;
-    use crate::day13;
-    use crate::day14;
-    use crate::day15;
-    use std::fs;
-    use std::path::Path;
-    use std::str::FromStr;
-
-    fn read_output<P: AsRef<Path>, T: FromStr>(out_file: P) -> T
-    where
-        <T as std::str::FromStr>::Err: std::fmt::Debug,
-    {
-        fs::read_to_string(out_file)
-            .expect("failed to read out file")
-            .parse()
-            .expect("failed to parse output")
-    }
+    use std::path::{Path, PathBuf};
 
-    fn out_path(file: &str) -> String {
-        format!("outputs/{}", file)
-    }
+    use advent_of_code_2022::baseline::{self, BASELINE_FILE};
+    use advent_of_code_2022::input::{self, InputSource};
+    use advent_of_code_2022::solutions;
+    use advent_of_code_2022::verify::{self, Verdict};
+
+    use super::{select_profiles, BaselineOptions};
 
+    /// Checks every registered task against its recorded answer, for the
+    /// default inputs and for every profile of each year. Days without a local
+    /// input or output file are skipped, so the suite passes without them.
     #[test]
     fn test_with_inputs() {
-        assert_eq!(
-            day_1_1(include_str!("../inputs/01.txt")).expect("day 1_1 failed"),
-            read_output(out_path("01.1.txt"))
-        );
-        assert_eq!(
-            day_1_2(include_str!("../inputs/01.txt")).expect("day 1_2 failed"),
-            read_output(out_path("01.2.txt"))
-        );
+        let mut failures = Vec::new();
 
-        assert_eq!(
-            day_2_1(include_str!("../inputs/02.txt")).expect("day 2_1 failed"),
-            read_output(out_path("02.1.txt"))
-        );
-        assert_eq!(
-            day_2_2(include_str!("../inputs/02.txt")).expect("day 2_2 failed"),
-            read_output(out_path("02.2.txt"))
-        );
-
-        assert_eq!(
-            day03::task_1(include_str!("../inputs/03.txt")).expect("day 3_1 failed"),
-            read_output(out_path("03.1.txt"))
-        );
-        assert_eq!(
-            day03::task_2(include_str!("../inputs/03.txt")).expect("day 3_2 failed"),
-            read_output(out_path("03.2.txt"))
-        );
-
-        assert_eq!(
-            day04::task_1(include_str!("../inputs/04.txt")).expect("day 4_1 failed"),
-            read_output(out_path("04.1.txt"))
-        );
-        assert_eq!(
-            day04::task_2(include_str!("../inputs/04.txt")).expect("day 4_2 failed"),
-            read_output(out_path("04.2.txt"))
-        );
-
-        assert_eq!(
-            day05::task_1(include_str!("../inputs/05.txt")).expect("day 5_1 failed"),
-            read_output::<String, String>(out_path("05.1.txt"))
-        );
-        assert_eq!(
-            day05::task_2(include_str!("../inputs/05.txt")).expect("day 5_2 failed"),
-            read_output::<String, String>(out_path("05.2.txt"))
-        );
+        for year in solutions::years() {
+            let default = InputSource::from_args(None, None).with_year(year);
+            let mut profiles = vec![(String::new(), default.clone())];
+            if let InputSource::Dir(dir) = &default {
+                for name in input::list_profiles(dir).unwrap_or_default() {
+                    let source = default.with_profile(&name);
+                    profiles.push((name, source));
+                }
+            }
 
-        assert_eq!(
-            day06::task_1(include_str!("../inputs/06.txt")).expect("day 6_1 failed"),
-            read_output(out_path("06.1.txt"))
-        );
-        assert_eq!(
-            day06::task_2(include_str!("../inputs/06.txt")).expect("day 6_2 failed"),
-            read_output(out_path("06.2.txt"))
-        );
+            for (name, source) in &profiles {
+                let solutions: Vec<_> = solutions::all()
+                    .filter(|s| s.year == year && source.load(s.day).is_ok())
+                    .collect();
+                let outputs_dir = input::year_dir(verify::DEFAULT_OUTPUTS_DIR, year).join(name);
 
-        assert_eq!(
-            day07::task_1(include_str!("../inputs/07.txt")).expect("day 7_1 failed"),
-            read_output(out_path("07.1.txt"))
-        );
-        assert_eq!(
-            day07::task_2(include_str!("../inputs/07.txt")).expect("day 7_2 failed"),
-            read_output(out_path("07.2.txt"))
-        );
-
-        assert_eq!(
-            day08::task_1(include_str!("../inputs/08.txt")).expect("day 8_1 failed"),
-            read_output(out_path("08.1.txt"))
-        );
-        assert_eq!(
-            day08::task_2(include_str!("../inputs/08.txt")).expect("day 8_2 failed"),
-            read_output(out_path("08.2.txt"))
-        );
-
-        assert_eq!(
-            day09::task_1(include_str!("../inputs/09.txt")).expect("day 9_1 failed"),
-            read_output(out_path("09.1.txt"))
-        );
-        assert_eq!(
-            day09::task_2(include_str!("../inputs/09.txt")).expect("day 9_2 failed"),
-            read_output(out_path("09.2.txt"))
-        );
-
-        assert_eq!(
-            day10::task_1(include_str!("../inputs/10.txt")).expect("day 10_1 failed"),
-            read_output(out_path("10.1.txt"))
-        );
-        assert_eq!(
-            day10::task_2(include_str!("../inputs/10.txt")).expect("day 10_2 failed"),
-            read_output::<_, String>(out_path("10.2.txt"))
-        );
+                for v in verify::verify(&solutions, source, &outputs_dir, false) {
+                    match v.verdict {
+                        Verdict::Pass => {}
+                        Verdict::Missing => eprintln!(
+                            "Skipping {} {} day {} task {}: no output file",
+                            year, name, v.result.day, v.result.part
+                        ),
+                        verdict => failures.push(format!(
+                            "{} {} day {}_{}: {}",
+                            year, name, v.result.day, v.result.part, verdict
+                        )),
+                    }
+                }
+            }
+        }
 
-        assert_eq!(
-            day11::task_1(include_str!("../inputs/11.txt")).expect("day 11_1 failed"),
-            read_output(out_path("11.1.txt"))
-        );
-        assert_eq!(
-            day11::task_2(include_str!("../inputs/11.txt")).expect("day 11_2 failed"),
-            read_output(out_path("11.2.txt"))
-        );
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
 
-        assert_eq!(
-            day12::task_1(include_str!("../inputs/12.txt")).expect("day 12_1 failed"),
-            read_output(out_path("12.1.txt"))
-        );
-        assert_eq!(
-            day12::task_2(include_str!("../inputs/12.txt")).expect("day 12_2 failed"),
-            read_output(out_path("12.2.txt"))
-        );
+    #[test]
+    fn test_baseline_per_profile() {
+        let source = InputSource::Dir(PathBuf::from("inputs"));
+        let outputs = Path::new("outputs");
+        let alice = &select_profiles(&source, outputs, Some("alice".into()), false)[0];
+        let bob = &select_profiles(&source, outputs, Some("bob".into()), false)[0];
 
-        assert_eq!(
-            day13::task_1(include_str!("../inputs/13.txt")).expect("day 13_1 failed"),
-            read_output(out_path("13.1.txt"))
-        );
-        assert_eq!(
-            day13::task_2(include_str!("../inputs/13.txt")).expect("day 13_2 failed"),
-            read_output(out_path("13.2.txt"))
-        );
+        let mut opts = BaselineOptions {
+            path: None,
+            save: true,
+            compare: true,
+            threshold: baseline::DEFAULT_THRESHOLD,
+        };
+        assert_eq!(opts.path(alice), outputs.join("alice").join(BASELINE_FILE));
+        assert_eq!(opts.path(bob), outputs.join("bob").join(BASELINE_FILE));
 
-        assert_eq!(
-            day14::task_1(include_str!("../inputs/14.txt")).expect("day 14_1 failed"),
-            read_output(out_path("14.1.txt"))
-        );
-        assert_eq!(
-            day14::task_2(include_str!("../inputs/14.txt")).expect("day 14_2 failed"),
-            read_output(out_path("14.2.txt"))
-        );
+        opts.path = Some(PathBuf::from("shared.txt"));
+        assert_eq!(opts.path(alice), PathBuf::from("shared.txt"));
+        assert_eq!(opts.path(bob), PathBuf::from("shared.txt"));
     }
 }
@@ -0,0 +1,188 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Error of parsing puzzle input pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Day of the puzzle, 0 if the error was not attributed to a day yet.
+    pub day: u32,
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column in characters, starting at 1.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Display) -> Self {
+        ParseError {
+            day: 0,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn with_day(mut self, day: u32) -> Self {
+        self.day = day;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.day != 0 {
+            write!(f, "day {}, ", self.day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}: {:?}",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Helper for parsing puzzle input that creates [`ParseError`]s with correct
+/// positions.
+///
+/// Every `&str` passed to its methods has to be a slice of the input the
+/// parser was created with, which is the case when it comes from `split`,
+/// `lines` etc. Position of the error is derived from where the slice points
+/// to.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    day: u32,
+    input: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(day: u32, input: &'a str) -> Self {
+        Parser { day, input }
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// Line and column of the start of `text`.
+    pub fn position(&self, text: &str) -> (usize, usize) {
+        let start = self.input.as_ptr() as usize;
+        let ptr = text.as_ptr() as usize;
+        // Text that is not a slice of the input points at its start.
+        let offset = match ptr.checked_sub(start) {
+            Some(offset) if offset <= self.input.len() && self.input.is_char_boundary(offset) => {
+                offset
+            }
+            _ => 0,
+        };
+
+        let before = &self.input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+        (line, column)
+    }
+
+    pub fn error(&self, text: &str, message: impl Display) -> ParseError {
+        let (line, column) = self.position(text);
+        ParseError::new(line, column, text, message).with_day(self.day)
+    }
+
+    pub fn parse<T>(&self, text: &'a str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        text.parse()
+            .map_err(|e| self.error(text, format!("invalid value ({})", e)))
+    }
+
+    pub fn strip_prefix(&self, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        text.strip_prefix(prefix)
+            .ok_or_else(|| self.error(text, format!("expected {:?}", prefix)))
+    }
+
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, format!("expected {:?}", delimiter)))
+    }
+
+    /// Moves error reported relative to `part` (for example by
+    /// [`FromStr::from_str`] of a single line) to its position in the input.
+    pub fn within<T>(&self, part: &'a str, res: Result<T, ParseError>) -> Result<T, ParseError> {
+        res.map_err(|err| {
+            let (line, column) = self.position(part);
+            let column = match err.line {
+                1 => column + err.column - 1,
+                _ => err.column,
+            };
+            ParseError {
+                day: self.day,
+                line: line + err.line - 1,
+                column,
+                ..err
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ParseError, Parser};
+
+    const INPUT: &str = "1,2\n3,x\n\n4,5";
+
+    #[test]
+    fn test_position() {
+        let p = Parser::new(1, INPUT);
+        let lines: Vec<&str> = INPUT.lines().collect();
+
+        assert_eq!(p.position(lines[0]), (1, 1));
+        assert_eq!(p.position(&lines[1][2..]), (2, 3));
+        assert_eq!(p.position(lines[2]), (3, 1));
+        assert_eq!(p.position(lines[3]), (4, 1));
+        assert_eq!(p.position("other"), (1, 1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let p = Parser::new(4, INPUT);
+        let line = INPUT.lines().nth(1).unwrap();
+        let (_, y) = p.split_once(line, ",").unwrap();
+
+        let err = p.parse::<u32>(y).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (4, 2, 3));
+        assert_eq!(err.text, "x");
+        assert_eq!(
+            err.to_string(),
+            "day 4, line 2, column 3: invalid value (invalid digit found in string): \"x\""
+        );
+
+        let err = p.split_once(line, ";").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected \";\"");
+    }
+
+    #[test]
+    fn test_within() {
+        let p = Parser::new(2, INPUT);
+        let line = INPUT.lines().nth(3).unwrap();
+
+        let res: Result<(), _> = Err(ParseError::new(1, 3, "5", "bad"));
+        let err = p.within(line, res).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (2, 4, 3));
+
+        let block = &INPUT[4..];
+        let res: Result<(), _> = Err(ParseError::new(2, 1, "", "bad"));
+        let err = p.within(block, res).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
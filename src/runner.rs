@@ -0,0 +1,519 @@
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cancel;
+use crate::input::InputSource;
+use crate::solutions::{self, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Failed(String),
+    Panicked(String),
+    /// Did not finish within the timeout.
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Failed(err) => write!(f, "error: {}", err),
+            Status::Panicked(msg) => write!(f, "panic: {}", msg),
+            Status::TimedOut(timeout) => write!(f, "timed out after {}", format_duration(*timeout)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    /// One JSON object per line for each run.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "text" => OutputFormat::Text,
+            "json" => OutputFormat::Json,
+            s => anyhow::bail!("unexpected output format: {}", s),
+        })
+    }
+}
+
+/// Outcome of running a single solution.
+#[derive(Debug, Clone)]
+pub struct RunResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+impl RunResult {
+    pub fn to_json(&self) -> String {
+        let (status, error) = match &self.status {
+            Status::Ok => ("ok", None),
+            Status::Failed(err) => ("error", Some(err.clone())),
+            Status::Panicked(msg) => ("panic", Some(msg.clone())),
+            Status::TimedOut(_) => ("timeout", Some(self.status.to_string())),
+        };
+
+        format!(
+            r#"{{"year":{},"day":{},"part":{},"answer":{},"duration_ns":{},"status":"{}","error":{}}}"#,
+            self.year,
+            self.day,
+            self.part,
+            json_string(self.answer.as_deref()),
+            self.duration.as_nanos(),
+            status,
+            json_string(error.as_deref()),
+        )
+    }
+}
+
+fn json_string(s: Option<&str>) -> String {
+    let Some(s) = s else {
+        return "null".to_string();
+    };
+
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Parses day selection: `all`, a single day (`7`) or a range (`3..9`,
+/// `3..=9`).
+pub fn parse_days(s: &str) -> anyhow::Result<RangeInclusive<u32>> {
+    if s == "all" {
+        return Ok(0..=u32::MAX);
+    }
+
+    let range = if let Some((start, end)) = s.split_once("..=") {
+        start.parse()?..=end.parse()?
+    } else if let Some((start, end)) = s.split_once("..") {
+        let end: u32 = end.parse()?;
+        if end == 0 {
+            anyhow::bail!("empty day range: {}", s);
+        }
+        start.parse()?..=end - 1
+    } else {
+        let day = s.parse()?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        anyhow::bail!("empty day range: {}", s);
+    }
+    Ok(range)
+}
+
+pub fn select(year: u32, days: &RangeInclusive<u32>) -> Vec<&'static Solution> {
+    solutions::all()
+        .filter(|s| s.year == year && days.contains(&s.day))
+        .collect()
+}
+
+/// Runs the solution catching both errors and panics.
+pub fn run_solution(solution: &Solution, input: &str) -> RunResult {
+    let start = Instant::now();
+    let out = panic::catch_unwind(AssertUnwindSafe(|| solution.run(input)));
+    let duration = start.elapsed();
+
+    let (answer, status) = match out {
+        Ok(Ok(answer)) => (Some(answer.to_string()), Status::Ok),
+        Ok(Err(err)) => (None, Status::Failed(format!("{:#}", err))),
+        Err(payload) => (None, Status::Panicked(panic_message(payload.as_ref()))),
+    };
+
+    RunResult {
+        year: solution.year,
+        day: solution.day,
+        part: solution.part,
+        answer,
+        duration,
+        status,
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Runs the solution on a separate thread and gives up waiting for it after
+/// `timeout`. The solution is then cancelled, which stops it at its next
+/// [`cancel::check`] point; solutions without one keep running in the
+/// background until the process exits.
+pub fn run_with_timeout(solution: &Solution, input: &str, timeout: Option<Duration>) -> RunResult {
+    let Some(timeout) = timeout else {
+        return run_solution(solution, input);
+    };
+
+    let token = Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = mpsc::channel();
+    let (thread_solution, input, thread_token) = (*solution, input.to_string(), token.clone());
+    thread::spawn(move || {
+        let res = cancel::with_token(thread_token, || run_solution(&thread_solution, &input));
+        let _ = sender.send(res);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(res) => res,
+        Err(_) => {
+            token.store(true, Ordering::Relaxed);
+            RunResult {
+                year: solution.year,
+                day: solution.day,
+                part: solution.part,
+                answer: None,
+                duration: timeout,
+                status: Status::TimedOut(timeout),
+            }
+        }
+    }
+}
+
+/// Loads input of every day of the solutions once. Loading errors are kept
+/// per day so a missing input does not stop the whole run.
+pub fn load_inputs(
+    solutions: &[&Solution],
+    source: &InputSource,
+) -> HashMap<u32, Result<String, String>> {
+    let mut inputs = HashMap::new();
+    for solution in solutions {
+        inputs
+            .entry(solution.day)
+            .or_insert_with(|| source.load(solution.day).map_err(|e| format!("{:#}", e)));
+    }
+    inputs
+}
+
+/// Result of a solution that could not run because its input failed to load.
+pub fn load_failure(solution: &Solution, err: &str) -> RunResult {
+    RunResult {
+        year: solution.year,
+        day: solution.day,
+        part: solution.part,
+        answer: None,
+        duration: Duration::ZERO,
+        status: Status::Failed(err.to_string()),
+    }
+}
+
+/// Runs all solutions in order. Input of each day is loaded once and a day
+/// with missing input is reported as failed without stopping the run.
+pub fn run_all(solutions: &[&Solution], source: &InputSource) -> Vec<RunResult> {
+    run_parallel(solutions, source, 1, None)
+}
+
+/// Runs solutions on `jobs` threads and returns results in the order of
+/// `solutions`. Every solution is timed on the thread running it and
+/// reported as timed out if it takes longer than `timeout`.
+pub fn run_parallel(
+    solutions: &[&Solution],
+    source: &InputSource,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let inputs = load_inputs(solutions, source);
+    let run = |solution: &Solution| match &inputs[&solution.day] {
+        Ok(input) => run_with_timeout(solution, input, timeout),
+        Err(err) => load_failure(solution, err),
+    };
+
+    if jobs <= 1 {
+        return solutions.iter().map(|s| run(s)).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(solutions.len()) {
+            let sender = sender.clone();
+            let (run, next) = (&run, &next);
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(solution) = solutions.get(idx) else {
+                    break;
+                };
+                if sender.send((idx, run(solution))).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<(usize, RunResult)> = receiver.into_iter().collect();
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, res)| res).collect()
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+/// Prints results as a table. Multi-line answers (like day 10 CRT output) do
+/// not fit into a cell so they are printed below the table.
+pub fn print_table(results: &[RunResult]) {
+    let answers: Vec<String> = results
+        .iter()
+        .map(|r| match &r.answer {
+            Some(a) if a.trim_end().contains('\n') => "(see below)".to_string(),
+            Some(a) => a.clone(),
+            None => "-".to_string(),
+        })
+        .collect();
+    let answer_width = answers.iter().map(|a| a.chars().count()).max().unwrap_or(0);
+    let answer_width = answer_width.max("Answer".len());
+
+    println!(
+        "{:>3}  {:>4}  {:<aw$}  {:>10}  Status",
+        "Day",
+        "Part",
+        "Answer",
+        "Time",
+        aw = answer_width
+    );
+    for (r, answer) in results.iter().zip(&answers) {
+        println!(
+            "{:>3}  {:>4}  {:<aw$}  {:>10}  {}",
+            r.day,
+            r.part,
+            answer,
+            format_duration(r.duration),
+            r.status,
+            aw = answer_width
+        );
+    }
+
+    let total: Duration = results.iter().map(|r| r.duration).sum();
+    let failed = results.iter().filter(|r| r.status != Status::Ok).count();
+    println!(
+        "{} solutions, {} failed, total time {}",
+        results.len(),
+        failed,
+        format_duration(total)
+    );
+
+    for (r, answer) in results.iter().zip(&answers) {
+        if let (Some(a), "(see below)") = (&r.answer, answer.as_str()) {
+            println!("\nDay {} part {}:\n{}", r.day, r.part, a.trim_end());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::{
+        format_duration, parse_days, run_all, run_parallel, run_solution, run_with_timeout, select,
+        RunResult, Status,
+    };
+    use crate::cancel;
+    use crate::input::InputSource;
+    use crate::solutions::{find, Solution};
+    use crate::util::temp_file_with_content;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7").unwrap(), 7..=7);
+        assert_eq!(parse_days("3..=9").unwrap(), 3..=9);
+        assert_eq!(parse_days("3..9").unwrap(), 3..=8);
+        assert!(parse_days("all").unwrap().contains(&15));
+        assert!(parse_days("9..=3").is_err());
+        assert!(parse_days("3..3").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_select() {
+        let selected = select(2022, &(3..=4));
+        let keys: Vec<_> = selected.iter().map(|s| (s.day, s.part)).collect();
+        assert_eq!(keys, vec![(3, 1), (3, 2), (4, 1), (4, 2)]);
+        assert!(select(2015, &(3..=4)).is_empty());
+    }
+
+    #[test]
+    fn test_run_solution_catches_errors() {
+        let ok = run_solution(find(2022, 2, 1).unwrap(), "A Y\nB X\nC Z");
+        assert_eq!(ok.status, Status::Ok);
+        assert_eq!(ok.answer.as_deref(), Some("15"));
+
+        let failed = run_solution(find(2022, 2, 1).unwrap(), "A Q");
+        assert!(matches!(
+            failed.status,
+            Status::Failed(_) | Status::Panicked(_)
+        ));
+        assert_eq!(failed.answer, None);
+
+        let panicking = Solution {
+            year: 2022,
+            day: 0,
+            part: 1,
+            run: |_| panic!("boom"),
+            phases: None,
+            naive: None,
+            examples: &[],
+        };
+        let res = run_solution(&panicking, "");
+        assert_eq!(res.status, Status::Panicked("boom".to_string()));
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        let input = temp_file_with_content("aoc_2022_parallel_input.txt", "A Y\nB X\nC Z");
+        let source = InputSource::File(input);
+        let solutions = [
+            find(2022, 2, 1).unwrap(),
+            find(2022, 2, 2).unwrap(),
+            find(2022, 1, 1).unwrap(),
+            find(2022, 2, 1).unwrap(),
+        ];
+
+        let expected = run_all(&solutions, &source);
+        for jobs in [1, 3, 8] {
+            let results = run_parallel(&solutions, &source, jobs, None);
+            let keys: Vec<_> = results
+                .iter()
+                .map(|r| (r.day, r.part, r.answer.clone(), r.status.clone()))
+                .collect();
+            let expected_keys: Vec<_> = expected
+                .iter()
+                .map(|r| (r.day, r.part, r.answer.clone(), r.status.clone()))
+                .collect();
+            assert_eq!(keys, expected_keys, "jobs: {}", jobs);
+        }
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        static STOPPED: AtomicBool = AtomicBool::new(false);
+
+        let ok = run_with_timeout(
+            find(2022, 2, 1).unwrap(),
+            "A Y",
+            Some(Duration::from_secs(10)),
+        );
+        assert_eq!(ok.answer.as_deref(), Some("8"));
+
+        let endless = Solution {
+            year: 2022,
+            day: 0,
+            part: 1,
+            run: |_| loop {
+                if let Err(err) = cancel::check() {
+                    STOPPED.store(true, Ordering::SeqCst);
+                    return Err(err.into());
+                }
+                thread::sleep(Duration::from_millis(1));
+            },
+            phases: None,
+            naive: None,
+            examples: &[],
+        };
+        let timeout = Duration::from_millis(20);
+        let res = run_with_timeout(&endless, "", Some(timeout));
+        assert_eq!(res.status, Status::TimedOut(timeout));
+
+        let start = Instant::now();
+        while !STOPPED.load(Ordering::SeqCst) {
+            assert!(start.elapsed() < Duration::from_secs(5), "not cancelled");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        let res = RunResult {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: Some("#.\n\"x\"\t".to_string()),
+            duration: Duration::from_micros(3),
+            status: Status::Ok,
+        };
+        assert_eq!(
+            res.to_json(),
+            r##"{"year":2022,"day":10,"part":2,"answer":"#.\n\"x\"\t","duration_ns":3000,"status":"ok","error":null}"##
+        );
+
+        let res = RunResult {
+            year: 2022,
+            day: 1,
+            part: 1,
+            answer: None,
+            duration: Duration::ZERO,
+            status: Status::Failed("bad\u{1}".to_string()),
+        };
+        assert_eq!(
+            res.to_json(),
+            r#"{"year":2022,"day":1,"part":1,"answer":null,"duration_ns":0,"status":"error","error":"bad\u0001"}"#
+        );
+
+        let res = RunResult {
+            year: 2022,
+            day: 14,
+            part: 2,
+            answer: None,
+            duration: Duration::from_secs(1),
+            status: Status::TimedOut(Duration::from_secs(1)),
+        };
+        assert_eq!(
+            res.to_json(),
+            r#"{"year":2022,"day":14,"part":2,"answer":null,"duration_ns":1000000000,"status":"timeout","error":"timed out after 1.00s"}"#
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(500)), "500ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2.00s");
+    }
+}
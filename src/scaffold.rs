@@ -0,0 +1,216 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::input::input_path;
+use crate::verify::output_path;
+
+/// Source of a new day module. Tasks fail until they are implemented and the
+/// tests are ignored so the tree stays green.
+pub fn day_template(day: u32) -> String {
+    format!(
+        r#"use crate::solutions::Example;
+
+pub fn task_1(_input: &str) -> anyhow::Result<usize> {{
+    anyhow::bail!("day {day} task 1 is not implemented")
+}}
+
+pub fn task_2(_input: &str) -> anyhow::Result<usize> {{
+    anyhow::bail!("day {day} task 2 is not implemented")
+}}
+
+const INPUT: &str = r"";
+
+pub const EXAMPLES: &[Example] = &[Example {{
+    name: "1",
+    input: INPUT,
+    expected: [None, None],
+}}];
+
+#[cfg(test)]
+mod test {{
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+
+    #[test]
+    #[ignore = "not implemented"]
+    fn test_1() {{
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 0);
+    }}
+
+    #[test]
+    #[ignore = "not implemented"]
+    fn test_2() {{
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 0);
+    }}
+}}
+"#
+    )
+}
+
+/// Adds `pub mod dayNN;` and solutions of both tasks to the year module,
+/// keeping days in order.
+pub fn register_day(year_mod: &str, day: u32) -> anyhow::Result<String> {
+    let module = format!("day{:02}", day);
+    let mod_line = format!("pub mod {};", module);
+    anyhow::ensure!(
+        !year_mod.lines().any(|l| l.trim() == mod_line),
+        "{} is already registered",
+        module
+    );
+
+    let mut lines: Vec<String> = year_mod.lines().map(String::from).collect();
+
+    let mods: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod day"))
+        .collect();
+    let last_mod = *mods.last().context("no day modules found")?;
+    let mod_idx = mods
+        .iter()
+        .copied()
+        .find(|&i| lines[i].as_str() > mod_line.as_str())
+        .unwrap_or(last_mod + 1);
+    lines.insert(mod_idx, mod_line);
+
+    let entry_day = |line: &str| -> Option<u32> {
+        let args = line.trim().strip_prefix("solution!(")?;
+        args.split(',').nth(1)?.trim().parse().ok()
+    };
+    let end = lines
+        .iter()
+        .position(|l| l == "];")
+        .context("end of SOLUTIONS not found")?;
+    let entry_idx = (0..end)
+        .find(|&i| entry_day(&lines[i]).is_some_and(|d| d > day))
+        .unwrap_or(end);
+    for part in [2, 1] {
+        lines.insert(
+            entry_idx,
+            format!(
+                "    solution!(YEAR, {}, {}, {}::task_{}),",
+                day, part, module, part
+            ),
+        );
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Ok(out)
+}
+
+/// Creates the module of a new day in `src_dir/y<year>`, registers it and
+/// adds empty input and answer files to the inputs and outputs directories of
+/// the year. Nothing is written if any of the files already exists. Returns
+/// the created files.
+pub fn new_day(
+    src_dir: &Path,
+    inputs_dir: &Path,
+    outputs_dir: &Path,
+    year: u32,
+    day: u32,
+) -> anyhow::Result<Vec<PathBuf>> {
+    anyhow::ensure!((1..=25).contains(&day), "day has to be between 1 and 25");
+
+    let year_dir_src = src_dir.join(format!("y{}", year));
+    let mod_path = year_dir_src.join("mod.rs");
+    let year_mod = fs::read_to_string(&mod_path)
+        .with_context(|| format!("failed to read {}", mod_path.display()))?;
+
+    let module_path = year_dir_src.join(format!("day{:02}.rs", day));
+    let placeholders = [
+        input_path(inputs_dir, day),
+        output_path(outputs_dir, day, 1),
+        output_path(outputs_dir, day, 2),
+    ];
+
+    for path in std::iter::once(&module_path).chain(&placeholders) {
+        anyhow::ensure!(!path.exists(), "{} already exists", path.display());
+    }
+    let year_mod = register_day(&year_mod, day)?;
+
+    fs::write(&module_path, day_template(day))
+        .with_context(|| format!("failed to write {}", module_path.display()))?;
+    fs::write(&mod_path, year_mod)
+        .with_context(|| format!("failed to write {}", mod_path.display()))?;
+    for path in &placeholders {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        fs::write(path, "").with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    let mut created = vec![module_path, mod_path];
+    created.extend(placeholders);
+    Ok(created)
+}
+
+#[cfg(test)]
+mod test {
+    use std::env::temp_dir;
+    use std::fs;
+
+    use super::{new_day, register_day};
+
+    const YEAR_MOD: &str = "use crate::solutions::{solution, Solution};
+
+pub mod day01;
+pub mod day03;
+
+pub const YEAR: u32 = 2022;
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(YEAR, 1, 1, day01::task_1),
+    solution!(YEAR, 1, 2, day01::task_2),
+    solution!(YEAR, 3, 1, day03::task_1, day03::parse => day03::solve_1),
+];
+";
+
+    #[test]
+    fn test_register_day() {
+        let out = register_day(YEAR_MOD, 2).unwrap();
+        assert!(out.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(out.contains(
+            "day01::task_2),
+    solution!(YEAR, 2, 1, day02::task_1),
+    solution!(YEAR, 2, 2, day02::task_2),
+    solution!(YEAR, 3, 1,"
+        ));
+
+        let out = register_day(YEAR_MOD, 12).unwrap();
+        assert!(out.contains("pub mod day03;\npub mod day12;\n\npub const YEAR"));
+        assert!(out.contains("day03::solve_1),\n    solution!(YEAR, 12, 1, day12::task_1),\n    solution!(YEAR, 12, 2, day12::task_2),\n];"));
+
+        assert!(register_day(YEAR_MOD, 3).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let mut root = temp_dir();
+        root.push("aoc_2022_scaffold");
+        let _ = fs::remove_dir_all(&root);
+        let (src, inputs, outputs) = (root.join("src"), root.join("in"), root.join("out"));
+        fs::create_dir_all(src.join("y2022")).unwrap();
+        fs::write(src.join("y2022/mod.rs"), YEAR_MOD).unwrap();
+
+        let created = new_day(&src, &inputs, &outputs, 2022, 2).unwrap();
+        assert_eq!(created.len(), 5);
+        assert!(fs::read_to_string(src.join("y2022/day02.rs"))
+            .unwrap()
+            .contains("day 2 task 1 is not implemented"));
+        assert_eq!(fs::read_to_string(inputs.join("02.txt")).unwrap(), "");
+        assert!(outputs.join("02.2.txt").exists());
+
+        // Refuses to overwrite, also when only a placeholder exists.
+        assert!(new_day(&src, &inputs, &outputs, 2022, 2).is_err());
+        fs::write(inputs.join("05.txt"), "input").unwrap();
+        let err = new_day(&src, &inputs, &outputs, 2022, 5).unwrap_err();
+        assert!(err.to_string().contains("05.txt already exists"));
+        assert!(!src.join("y2022/day05.rs").exists());
+
+        assert!(new_day(&src, &inputs, &outputs, 2022, 26).is_err());
+        assert!(new_day(&src, &inputs, &outputs, 2015, 1).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::y2022;
+
+pub type SolutionFn = fn(&str) -> anyhow::Result<Box<dyn Display>>;
+
+/// Runs the task as separate parse and solve steps and returns time spent in
+/// each of them.
+pub type PhasesFn = fn(&str) -> anyhow::Result<(Duration, Duration)>;
+
+/// Example input from the puzzle description together with its answers.
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    /// Expected answers of part 1 and 2, `None` if the example does not apply
+    /// to the part.
+    pub expected: [Option<&'static str>; 2],
+}
+
+/// Single registered task of a day.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub run: SolutionFn,
+    /// Set for days that have a separate parsing step.
+    pub phases: Option<PhasesFn>,
+    /// Slower, straightforward implementation used to cross-check `run`.
+    pub naive: Option<SolutionFn>,
+    /// All examples of the day, including ones without answer for this part.
+    pub examples: &'static [Example],
+}
+
+impl Solution {
+    pub fn run(&self, input: &str) -> anyhow::Result<Box<dyn Display>> {
+        (self.run)(input)
+    }
+
+    /// Finds example applicable to this part by its name.
+    pub fn example(&self, name: &str) -> Option<&'static Example> {
+        self.examples().find(|e| e.name == name)
+    }
+
+    /// Examples that have an expected answer for this part.
+    pub fn examples(&self) -> impl Iterator<Item = &'static Example> + '_ {
+        self.examples.iter().filter(|e| self.expected(e).is_some())
+    }
+
+    pub fn expected(&self, example: &Example) -> Option<&'static str> {
+        example.expected[self.part as usize - 1]
+    }
+}
+
+macro_rules! solution {
+    (@naive) => {
+        None
+    };
+    (@naive $naive:path) => {
+        Some(|input| Ok(Box::new($naive(input)?)))
+    };
+    ($year:expr, $day:expr, $part:expr, $module:ident::$func:ident $(, naive: $naive:path)?) => {
+        $crate::solutions::Solution {
+            year: $year,
+            day: $day,
+            part: $part,
+            run: |input| Ok(Box::new($module::$func(input)?)),
+            phases: None,
+            naive: $crate::solutions::solution!(@naive $($naive)?),
+            examples: $module::EXAMPLES,
+        }
+    };
+    (
+        $year:expr, $day:expr, $part:expr, $module:ident::$func:ident,
+        $parse:path => $solve:path $(, naive: $naive:path)?
+    ) => {
+        $crate::solutions::Solution {
+            year: $year,
+            day: $day,
+            part: $part,
+            run: |input| Ok(Box::new($module::$func(input)?)),
+            examples: $module::EXAMPLES,
+            naive: $crate::solutions::solution!(@naive $($naive)?),
+            phases: Some(|input| {
+                use std::hint::black_box;
+
+                let start = std::time::Instant::now();
+                let parsed = black_box($parse(black_box(input))?);
+                let parse_time = start.elapsed();
+
+                let start = std::time::Instant::now();
+                black_box($solve(parsed)?);
+                Ok((parse_time, start.elapsed()))
+            }),
+        }
+    };
+}
+
+pub(crate) use solution;
+
+/// Year used when none is selected. Its inputs and outputs live directly in
+/// the inputs and outputs directories, other years use `<year>/`
+/// subdirectories.
+pub const DEFAULT_YEAR: u32 = 2022;
+
+/// Solutions of every year, each ordered by day and part. A new year only
+/// needs to be added here.
+pub const YEARS: &[&[Solution]] = &[y2022::SOLUTIONS];
+
+/// All registered solutions ordered by year, day and part.
+pub fn all() -> impl Iterator<Item = &'static Solution> {
+    YEARS.iter().flat_map(|year| year.iter())
+}
+
+pub fn find(year: u32, day: u32, part: u32) -> Option<&'static Solution> {
+    all().find(|s| s.year == year && s.day == day && s.part == part)
+}
+
+/// Years that have at least one registered solution, in order.
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = all().map(|s| s.year).collect();
+    years.dedup();
+    years
+}
+
+/// Days of the year that have at least one registered solution, in order.
+pub fn days(year: u32) -> Vec<u32> {
+    let mut days: Vec<u32> = all().filter(|s| s.year == year).map(|s| s.day).collect();
+    days.dedup();
+    days
+}
+
+#[cfg(test)]
+mod test {
+    use super::{all, days, find, years};
+    use crate::verify::{check_answer, Verdict};
+
+    #[test]
+    fn test_registry_sorted_and_unique() {
+        let solutions: Vec<_> = all().collect();
+        for pair in solutions.windows(2) {
+            assert!(
+                (pair[0].year, pair[0].day, pair[0].part)
+                    < (pair[1].year, pair[1].day, pair[1].part),
+                "{} day {} part {} registered out of order",
+                pair[1].year,
+                pair[1].day,
+                pair[1].part
+            );
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let solution = find(2022, 2, 1).expect("day 2 part 1 registered");
+        assert_eq!(
+            solution
+                .run("A Y\nB X\nC Z")
+                .expect("failed to run")
+                .to_string(),
+            "15"
+        );
+        assert!(find(2022, 2, 3).is_none());
+        assert!(find(2022, 99, 1).is_none());
+        assert!(find(2015, 2, 1).is_none());
+    }
+
+    #[test]
+    fn test_examples() {
+        for solution in all() {
+            for example in solution.examples() {
+                let answer = solution
+                    .run(example.input)
+                    .unwrap_or_else(|e| panic!("day {} failed: {}", solution.day, e));
+                assert_eq!(
+                    check_answer(solution.expected(example), &answer.to_string()),
+                    Verdict::Pass,
+                    "day {} part {} example {}",
+                    solution.day,
+                    solution.part,
+                    example.name
+                );
+            }
+        }
+        assert!(find(2022, 9, 2).unwrap().example("2").is_some());
+        assert!(find(2022, 9, 1).unwrap().example("2").is_none());
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(years(), vec![2022]);
+        // Prefix only, so scaffolding a new day does not break the test.
+        assert!(days(2022).starts_with(&(1..=15).collect::<Vec<_>>()));
+        assert!(days(2015).is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+//! Randomized testing helpers: a small deterministic PRNG and a runner that
+//! reports the seed and input of a failing case.
+
+use std::panic::{self, AssertUnwindSafe};
+
+/// Number of random inputs checked by property tests of each day.
+pub const CASES: u64 = 200;
+
+/// SplitMix64 generator, good enough for test inputs and reproducible from
+/// the seed.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number in `lo..=hi`.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        let span = (hi - lo) as u64 + 1;
+        lo + (self.next_u64() % span) as i64
+    }
+
+    /// Uniform index in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// True with probability `percent`%.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Runs `check` on `cases` inputs made by `generate`. A failing case is
+/// reported with its seed and input so it can be turned into a regular test.
+pub fn for_random_inputs(cases: u64, generate: impl Fn(&mut Rng) -> String, check: impl Fn(&str)) {
+    for seed in 0..cases {
+        let input = generate(&mut Rng::new(seed));
+        if let Err(err) = panic::catch_unwind(AssertUnwindSafe(|| check(&input))) {
+            let message = err
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| err.downcast_ref::<&str>().copied())
+                .unwrap_or("panicked");
+            panic!("seed {} failed: {}\ninput:\n{}", seed, message, input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{for_random_inputs, Rng};
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let xs: Vec<_> = (0..5).map(|_| a.next_u64()).collect();
+        assert_eq!(xs, (0..5).map(|_| b.next_u64()).collect::<Vec<_>>());
+
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3, 3)));
+            assert!(rng.below(5) < 5);
+        }
+        assert_eq!(rng.range(4, 4), 4);
+
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_failure_reports_seed() {
+        let res = std::panic::catch_unwind(|| {
+            for_random_inputs(
+                10,
+                |rng| rng.range(0, 9).to_string(),
+                |input| assert_ne!(input, "3", "three"),
+            )
+        });
+        let err = res.unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("seed "));
+        assert!(message.contains("three"));
+        assert!(message.ends_with("input:\n3"));
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Verbosity of tracing output. Messages are printed to stderr only when
+/// their level is enabled, by default nothing is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off = 0,
+    /// Intermediate results worth seeing when something looks off (`-v`).
+    Debug = 1,
+    /// Detailed per-step state (`-vv`).
+    Trace = 2,
+}
+
+impl Level {
+    /// Level for the number of `-v` flags.
+    pub fn from_verbosity(verbosity: usize) -> Self {
+        match verbosity {
+            0 => Level::Off,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Off => write!(f, "off"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Prints the message. Use [`debug!`](macro@crate::debug) and
+/// [`trace!`](macro@crate::trace) instead, they skip formatting when the level
+/// is disabled.
+pub fn log(level: Level, target: &str, args: fmt::Arguments) {
+    let target = target.rsplit("::").next().unwrap_or(target);
+    eprintln!("[{} {}] {}", level, target, args);
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Debug) {
+            $crate::trace::log($crate::trace::Level::Debug, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Trace) {
+            $crate::trace::log($crate::trace::Level::Trace, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{enabled, Level};
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::from_verbosity(0), Level::Off);
+        assert_eq!(Level::from_verbosity(1), Level::Debug);
+        assert_eq!(Level::from_verbosity(5), Level::Trace);
+
+        // Tests run with the default level, which is silent.
+        assert!(!enabled(Level::Debug));
+        assert!(!enabled(Level::Trace));
+        assert!(!enabled(Level::Off));
+    }
+}
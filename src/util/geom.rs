@@ -0,0 +1,345 @@
+//! Signed 2D geometry shared by the days.
+//!
+//! Coordinates follow the screen and [`Grid`](super::Grid) convention: `x`
+//! grows to the right and `y` grows down, so [`Direction::Up`] is `(0, -1)`.
+//! Rotations are named by how they look on the screen.
+
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Difference between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> isize {
+        (self - other).manhattan_len()
+    }
+
+    pub fn chebyshev(self, other: Point) -> isize {
+        (self - other).chebyshev_len()
+    }
+
+    /// Orthogonal neighbours, clockwise starting from up.
+    pub fn neighbours_4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self + d.vec())
+    }
+
+    /// Orthogonal and diagonal neighbours, clockwise starting from up.
+    pub fn neighbours_8(self) -> impl Iterator<Item = Point> {
+        Direction8::ALL.into_iter().map(move |d| self + d.vec())
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vec2 { x, y }
+    }
+
+    pub fn manhattan_len(self) -> isize {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev_len(self) -> isize {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// Vector with both coordinates reduced to -1, 0 or 1.
+    pub fn signum(self) -> Vec2 {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub fn rotate_right(self) -> Vec2 {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90 degrees counterclockwise.
+    pub fn rotate_left(self) -> Vec2 {
+        Vec2::new(self.y, -self.x)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(d: Direction) -> Self {
+        d.vec()
+    }
+}
+
+impl From<Direction8> for Vec2 {
+    fn from(d: Direction8) -> Self {
+        d.vec()
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add<Vec2> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vec2) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vec2> for Point {
+    fn add_assign(&mut self, v: Vec2) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vec2> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vec2) -> Point {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl SubAssign<Vec2> for Point {
+    fn sub_assign(&mut self, v: Vec2) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Point) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, v: Vec2) -> Vec2 {
+        Vec2::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, v: Vec2) -> Vec2 {
+        Vec2::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, k: isize) -> Vec2 {
+        Vec2::new(self.x * k, self.y * k)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+/// One of the 4 orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions clockwise starting from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn vec(self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the 4 orthogonal or 4 diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions clockwise starting from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn vec(self) -> Vec2 {
+        match self {
+            Direction8::Up => Vec2::new(0, -1),
+            Direction8::UpRight => Vec2::new(1, -1),
+            Direction8::Right => Vec2::new(1, 0),
+            Direction8::DownRight => Vec2::new(1, 1),
+            Direction8::Down => Vec2::new(0, 1),
+            Direction8::DownLeft => Vec2::new(-1, 1),
+            Direction8::Left => Vec2::new(-1, 0),
+            Direction8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    /// Turns by 45 degrees clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        Direction8::ALL[d as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Direction, Direction8, Point, Vec2};
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point::new(2, -3);
+        let v = Vec2::new(-1, 5);
+        assert_eq!(p + v, Point::new(1, 2));
+        assert_eq!(p - v, Point::new(3, -8));
+        assert_eq!(Point::new(1, 2) - p, v);
+        assert_eq!(v + v, v * 2);
+        assert_eq!(v - v * 2, -v);
+
+        let mut q = p;
+        q += v;
+        q -= v * 2;
+        assert_eq!(q, Point::new(3, -8));
+        assert_eq!(Point::from((4, 5)), Point::new(4, 5));
+        assert_eq!(p.to_string(), "(2, -3)");
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 1);
+        let b = Point::new(-2, 3);
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(a.manhattan(a), 0);
+        assert_eq!((b - a).signum(), Vec2::new(-1, 1));
+        assert_eq!(Vec2::new(0, -7).signum(), Vec2::new(0, -1));
+    }
+
+    #[test]
+    fn test_rotation() {
+        for d in Direction::ALL {
+            assert_eq!(d.vec().rotate_right(), d.turn_right().vec());
+            assert_eq!(d.vec().rotate_left(), d.turn_left().vec());
+            assert_eq!(-d.vec(), d.opposite().vec());
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(Direction8::from(d).vec(), d.vec());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+
+        for d in Direction8::ALL {
+            assert_eq!(d.turn_right().turn_right().vec(), d.vec().rotate_right());
+            assert_eq!(-d.vec(), d.opposite().vec());
+        }
+        assert_eq!(Direction8::Down.turn_right(), Direction8::DownLeft);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point::new(0, 0);
+        assert_eq!(
+            p.neighbours_4().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+        assert_eq!(p.neighbours_8().count(), 8);
+        assert!(p.neighbours_8().all(|n| n.chebyshev(p) == 1));
+    }
+}
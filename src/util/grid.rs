@@ -0,0 +1,300 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use super::geom::{Direction, Direction8, Point, Vec2};
+
+/// Rectangular grid stored row by row in a single `Vec`.
+///
+/// Positions are `(x, y)` pairs, `x` being the column and `y` the row, with
+/// `(0, 0)` in the top left corner. Indexing with a position panics when it is
+/// out of bounds, [`Grid::get`] takes a signed [`Point`] and returns `None`
+/// instead, so that callers can step off the edge without checking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds the grid from rows, panics if they differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "rows of a grid must have the same length"
+        );
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Converts the point to a position if it is inside the grid.
+    pub fn position(&self, point: impl Into<Point>) -> Option<(usize, usize)> {
+        let point = point.into();
+        let (x, y) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    pub fn get(&self, pos: impl Into<Point>) -> Option<&T> {
+        let (x, y) = self.position(pos)?;
+        Some(&self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, pos: impl Into<Point>) -> Option<&mut T> {
+        let (x, y) = self.position(pos)?;
+        Some(&mut self.cells[y * self.width + x])
+    }
+
+    /// All positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, an empty grid has no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Orthogonal neighbours of the position that are inside the grid.
+    pub fn neighbours_4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let point = to_point(pos);
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.position(point + d.vec()))
+    }
+
+    /// Orthogonal and diagonal neighbours of the position that are inside the
+    /// grid.
+    pub fn neighbours_8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let point = to_point(pos);
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.position(point + d.vec()))
+    }
+
+    /// Cells from the position (excluded) in the direction until the edge of
+    /// the grid.
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        direction: impl Into<Vec2>,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let direction = direction.into();
+        assert!(direction != Vec2::ZERO, "ray needs a direction");
+        (1..)
+            .map(move |step| to_point(pos) + direction * step)
+            .map_while(|point| {
+                let pos = self.position(point)?;
+                Some((pos, &self[pos]))
+            })
+    }
+
+    /// Grid with rows and columns swapped.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+fn to_point((x, y): (usize, usize)) -> Point {
+    Point::new(x as isize, y as isize)
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) out of grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) out of grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Prints cells of every row next to each other, one row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid;
+    use crate::util::geom::{Direction, Point};
+
+    fn grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get((1, 1)), Some(&5));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&6));
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+
+        grid[(1, 0)] = 7;
+        *grid.get_mut((2, 1)).unwrap() = 8;
+        assert_eq!(grid.row(0), &[1, 7, 3]);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), vec![&3, &8]);
+        assert!(grid.get_mut((0, -1)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of grid")]
+    fn test_index_out_of_bounds() {
+        // Would wrap into the next row with flat storage.
+        let _ = grid()[(3, 0)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(
+            grid.neighbours_4((1, 1)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 1), (1, 2), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours_8((2, 0)).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 1), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3][..], &[4, 5, 6][..]]
+        );
+        assert_eq!(
+            grid.iter()
+                .filter(|(_, v)| **v % 2 == 0)
+                .collect::<Vec<_>>(),
+            vec![((1, 0), &2), ((0, 1), &4), ((2, 1), &6)]
+        );
+        assert_eq!(
+            grid.ray((0, 0), (1, 0)).collect::<Vec<_>>(),
+            vec![((1, 0), &2), ((2, 0), &3)]
+        );
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            vec![((1, 1), &5)]
+        );
+        assert_eq!(grid.ray((0, 1), (-1, 0)).count(), 0);
+        assert_eq!(
+            grid.ray((2, 1), Direction::Up).collect::<Vec<_>>(),
+            vec![((2, 0), &3)]
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        let transposed = grid().transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.row(2), &[3, 6]);
+        assert_eq!(transposed.transpose(), grid());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(grid().to_string(), "123\n456");
+        assert_eq!(
+            grid()
+                .map(|v| v % 2 == 0)
+                .map(|e| if *e { '#' } else { '.' })
+                .to_string(),
+            ".#.\n#.#"
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+
+/// Set of integers stored as sorted inclusive ranges.
+///
+/// Ranges are kept disjoint and ranges that touch are merged, so `1..=3` and
+/// `4..=6` are stored as `1..=6`. Empty ranges (`start > end`) are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet {
+    ranges: Vec<(isize, isize)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<isize>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Ranges in `first..last` overlap or touch the new one.
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e < start.saturating_sub(1));
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| s <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged ranges in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<isize>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// Number of integers in the set, which for the whole `isize` range does
+    /// not fit in 64 bits.
+    pub fn coverage(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(s, e)| (e as i128 - s as i128) as u128 + 1)
+            .sum()
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        self.contains_range(value..=value)
+    }
+
+    /// Whether the whole range is in the set, an empty range always is.
+    pub fn contains_range(&self, range: RangeInclusive<isize>) -> bool {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return true;
+        }
+        let i = self.ranges.partition_point(|&(_, e)| e < start);
+        self.ranges
+            .get(i)
+            .is_some_and(|&(s, e)| s <= start && end <= e)
+    }
+
+    /// Whether any integer of the range is in the set.
+    pub fn overlaps(&self, range: RangeInclusive<isize>) -> bool {
+        let (start, end) = range.into_inner();
+        let i = self.ranges.partition_point(|&(_, e)| e < start);
+        start <= end && self.ranges.get(i).is_some_and(|&(s, _)| s <= end)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intersection = IntervalSet::new();
+        let (mut i, mut k) = (0, 0);
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(k)) {
+            intersection.insert(s1.max(s2)..=e1.min(e2));
+            // Drop the range that ends first, it cannot overlap anything else.
+            match e1 < e2 {
+                true => i += 1,
+                false => k += 1,
+            }
+        }
+        intersection
+    }
+
+    /// Integers of this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = IntervalSet::new();
+        for &(start, end) in &self.ranges {
+            // Start of the part not removed yet, `None` once past `isize::MAX`.
+            let mut from = Some(start);
+            let first = other.ranges.partition_point(|&(_, e)| e < start);
+            for &(s, e) in other.ranges[first..].iter().take_while(|(s, _)| *s <= end) {
+                if let Some(from) = from.filter(|from| *from < s) {
+                    difference.insert(from..=s - 1);
+                }
+                from = e.checked_add(1);
+            }
+            if let Some(from) = from {
+                difference.insert(from..=end);
+            }
+        }
+        difference
+    }
+
+    /// Ranges within `bounds` that are not in the set.
+    pub fn gaps(
+        &self,
+        bounds: RangeInclusive<isize>,
+    ) -> impl Iterator<Item = RangeInclusive<isize>> {
+        let gaps = IntervalSet::from(bounds).difference(self);
+        gaps.ranges.into_iter().map(|(s, e)| s..=e)
+    }
+}
+
+impl From<RangeInclusive<isize>> for IntervalSet {
+    fn from(range: RangeInclusive<isize>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<isize>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<isize>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Prints ranges like `1-3 7-7`.
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (s, e)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}-{}", s, e)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use super::IntervalSet;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+
+    #[test]
+    fn test_insert() {
+        let mut set = IntervalSet::new();
+        set.insert(4..=6);
+        set.insert(1..=3);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=6]);
+
+        set.insert(10..=12);
+        set.insert(8..=8);
+        let (start, end) = (5, 4);
+        set.insert(start..=end);
+        assert_eq!(set.to_string(), "1-6 8-8 10-12");
+        set.insert(7..=9);
+        assert_eq!(set.to_string(), "1-12");
+        assert_eq!(set.coverage(), 12);
+
+        let set: IntervalSet = [isize::MIN..=0, 1..=isize::MAX].into_iter().collect();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![isize::MIN..=isize::MAX]
+        );
+        assert_eq!(set.coverage(), 1 << isize::BITS);
+        let rest = set.difference(&[isize::MIN..=-1, 5..=isize::MAX].into_iter().collect());
+        assert_eq!(rest.to_string(), "0-4");
+    }
+
+    #[test]
+    fn test_queries() {
+        let set: IntervalSet = [1..=3, 7..=9].into_iter().collect();
+        assert!(set.contains(3) && !set.contains(4));
+        assert!(set.contains_range(7..=9) && !set.contains_range(3..=7));
+        assert!(set.overlaps(3..=7) && !set.overlaps(4..=6));
+        assert_eq!(
+            set.gaps(0..=10).collect::<Vec<_>>(),
+            vec![0..=0, 4..=6, 10..=10]
+        );
+        assert_eq!(set.gaps(2..=3).count(), 0);
+    }
+
+    /// Two sets of ranges within -20..=20, one per line.
+    fn generate(rng: &mut Rng) -> String {
+        let set = |rng: &mut Rng| {
+            let ranges: Vec<String> = (0..rng.range(0, 5))
+                .map(|_| {
+                    let start = rng.range(-20, 20);
+                    format!("{}..{}", start, rng.range(start - 1, 20))
+                })
+                .collect();
+            ranges.join(" ")
+        };
+        format!("{}\n{}", set(rng), set(rng))
+    }
+
+    /// The set built from the ranges and the integers it should contain.
+    fn parse(line: &str) -> (IntervalSet, BTreeSet<isize>) {
+        let ranges: Vec<(isize, isize)> = line
+            .split_whitespace()
+            .map(|r| {
+                let (s, e) = r.split_once("..").unwrap();
+                (s.parse().unwrap(), e.parse().unwrap())
+            })
+            .collect();
+        (
+            ranges.iter().map(|&(s, e)| s..=e).collect(),
+            ranges.iter().flat_map(|&(s, e)| s..=e).collect(),
+        )
+    }
+
+    fn elements(set: &IntervalSet) -> BTreeSet<isize> {
+        // Ranges are sorted, disjoint and do not touch.
+        let ranges: Vec<_> = set.iter().collect();
+        for pair in ranges.windows(2) {
+            assert!(pair[0].end() + 1 < *pair[1].start(), "{} not merged", set);
+        }
+        assert!(ranges.iter().all(|r| r.start() <= r.end()));
+        set.iter().flatten().collect()
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            let (a, b) = input.split_once('\n').unwrap();
+            let ((a, a_model), (b, b_model)) = (parse(a), parse(b));
+
+            assert_eq!(elements(&a), a_model);
+            assert_eq!(a.coverage(), a_model.len() as u128);
+            assert_eq!(elements(&a.union(&b)), &a_model | &b_model);
+            assert_eq!(elements(&a.intersection(&b)), &a_model & &b_model);
+            assert_eq!(elements(&a.difference(&b)), &a_model - &b_model);
+
+            let gaps: BTreeSet<isize> = a.gaps(-10..=10).flatten().collect();
+            let expected: BTreeSet<isize> = (-10..=10).filter(|v| !a_model.contains(v)).collect();
+            assert_eq!(gaps, expected);
+
+            for range in b.iter() {
+                let all = range.clone().all(|v| a_model.contains(&v));
+                assert_eq!(a.contains_range(range.clone()), all);
+                assert_eq!(
+                    a.overlaps(range.clone()),
+                    range.clone().any(|v| a.contains(v))
+                );
+            }
+            for v in -21..=21 {
+                assert_eq!(a.contains(v), a_model.contains(&v));
+            }
+        });
+    }
+}
@@ -0,0 +1,154 @@
+use crate::parse::{ParseError, Parser};
+
+pub mod geom;
+mod grid;
+mod interval;
+pub mod read;
+pub mod search;
+
+pub use grid::Grid;
+pub use interval::IntervalSet;
+
+/// Writes the content to a file in the temporary directory for tests that
+/// read files.
+#[cfg(test)]
+pub fn temp_file_with_content(name: &str, content: &str) -> std::path::PathBuf {
+    let full_path = std::env::temp_dir().join(name);
+
+    std::fs::write(&full_path, content).expect("failed to write temp file");
+    full_path
+}
+
+/// Prepares raw puzzle input for solvers: strips BOM, converts CRLF line
+/// endings to LF and removes trailing blank lines together with the final
+/// newline. Leading and trailing spaces of lines are kept as some puzzles (day
+/// 5) depend on them.
+pub fn normalize_input(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut input = input.replace("\r\n", "\n");
+
+    let blank_tail = input
+        .rsplit('\n')
+        .take_while(|line| line.trim().is_empty())
+        .map(|line| line.len() + 1)
+        .sum::<usize>();
+    input.truncate(input.len().saturating_sub(blank_tail));
+    input
+}
+
+/// Reports whitespace in normalized input that is likely a copy-paste mistake
+/// and is not fixed by [`normalize_input`].
+pub fn input_warnings(input: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for (i, line) in input.split('\n').enumerate() {
+        let odd = line.chars().find(|c| {
+            *c == '\t' || *c == '\r' || *c == '\u{feff}' || (c.is_whitespace() && *c != ' ')
+        });
+        if let Some(c) = odd {
+            warnings.push(format!("line {}: unexpected whitespace {:?}", i + 1, c));
+        }
+    }
+    warnings
+}
+
+/// Converts test input to how it looks when saved on Windows: with BOM, CRLF
+/// line endings and trailing blank lines.
+#[cfg(test)]
+pub fn windows_input(input: &str) -> String {
+    format!("\u{feff}{}\r\n\r\n", input.replace('\n', "\r\n"))
+}
+
+pub fn parse_to_digit_grid(input: &str) -> Result<Grid<u32>, ParseError> {
+    let p = Parser::new(0, input);
+
+    let grid = input
+        .split("\n")
+        .map(|s| {
+            s.char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10)
+                        .ok_or_else(|| p.error(&s[i..i + c.len_utf8()], "expected digit"))
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<u32>>, _>>()?;
+
+    check_rectangular(&p, &grid)?;
+    Ok(Grid::from_rows(grid))
+}
+
+pub fn parse_to_char_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    let p = Parser::new(0, input);
+
+    let grid: Vec<Vec<char>> = input.split("\n").map(|s| s.chars().collect()).collect();
+
+    check_rectangular(&p, &grid)?;
+    Ok(Grid::from_rows(grid))
+}
+
+fn check_rectangular<T>(p: &Parser, grid: &[Vec<T>]) -> Result<(), ParseError> {
+    let width = grid[0].len();
+    for (row, line) in grid.iter().zip(p.input().split('\n')) {
+        if row.len() != width {
+            return Err(p.error(line, format!("expected {} columns", width)));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        input_warnings, normalize_input, parse_to_char_grid, parse_to_digit_grid, windows_input,
+        Grid,
+    };
+
+    #[test]
+    fn test_normalize_input() {
+        assert_eq!(normalize_input("12\n34"), "12\n34");
+        assert_eq!(normalize_input("\u{feff}12\r\n34\r\n"), "12\n34");
+        assert_eq!(normalize_input("1\n\n2\n\n  \n\n"), "1\n\n2");
+        assert_eq!(
+            normalize_input("    [D]\n[N] [C]  \n"),
+            "    [D]\n[N] [C]  "
+        );
+        assert_eq!(normalize_input("\r\n\n"), "");
+        assert_eq!(normalize_input(&windows_input("a\nb")), "a\nb");
+    }
+
+    #[test]
+    fn test_input_warnings() {
+        assert!(input_warnings("1 2\n  3  ").is_empty());
+        assert_eq!(
+            input_warnings("1\t2\n3\n4\u{a0}"),
+            vec![
+                "line 1: unexpected whitespace '\\t'".to_string(),
+                "line 3: unexpected whitespace '\\u{a0}'".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_to_digit_grid() {
+        assert_eq!(
+            parse_to_digit_grid("12\n34").unwrap(),
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]])
+        );
+
+        let err = parse_to_digit_grid("12\n3x").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, "x");
+
+        let err = parse_to_digit_grid("12\n345").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_parse_to_char_grid() {
+        assert_eq!(
+            parse_to_char_grid("ab\ncd").unwrap(),
+            Grid::from_rows(vec![vec!['a', 'b'], vec!['c', 'd']])
+        );
+        assert!(parse_to_char_grid("ab\nc").is_err());
+    }
+}
@@ -0,0 +1,395 @@
+//! Readers of line based input that stream from any [`BufRead`] (a file,
+//! stdin or a byte slice) and return errors instead of panicking.
+//!
+//! Lines are numbered from 1, line endings (LF or CRLF) and a BOM at the start
+//! are stripped. Unlike [`normalize_input`](super::normalize_input), [`lines`]
+//! keeps blank lines at the end, [`parse_lines`] and [`read_lines`] skip them.
+//!
+//! Parsing helpers take the day like [`Parser::new`](crate::parse::Parser::new)
+//! so that errors name it, 0 leaves it out.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+#[derive(Debug)]
+pub enum ReadError {
+    Open {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// Reading of the line failed, for example it is not valid UTF-8.
+    Io {
+        line: usize,
+        source: io::Error,
+    },
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Open { path, source } => {
+                write!(f, "failed to open {}: {}", path.display(), source)
+            }
+            ReadError::Io { line, source } => write!(f, "line {}: {}", line, source),
+            ReadError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Open { source, .. } | ReadError::Io { source, .. } => Some(source),
+            ReadError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        ReadError::Parse(err)
+    }
+}
+
+pub fn open<P: AsRef<Path>>(path: P) -> Result<BufReader<File>, ReadError> {
+    let path = path.as_ref();
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|source| ReadError::Open {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+/// Iterator over lines with their numbers, see [`lines`].
+pub struct Lines<R> {
+    reader: R,
+    line: usize,
+    failed: bool,
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<(usize, String), ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let mut text = String::new();
+        self.line += 1;
+        match self.reader.read_line(&mut text) {
+            Ok(0) => None,
+            Ok(_) => {
+                if text.ends_with('\n') {
+                    text.pop();
+                    if text.ends_with('\r') {
+                        text.pop();
+                    }
+                }
+                if self.line == 1 && text.starts_with('\u{feff}') {
+                    text.remove(0);
+                }
+                Some(Ok((self.line, text)))
+            }
+            Err(source) => {
+                // Errors such as invalid UTF-8 would repeat forever.
+                self.failed = true;
+                Some(Err(ReadError::Io {
+                    line: self.line,
+                    source,
+                }))
+            }
+        }
+    }
+}
+
+/// Lines of the reader together with their numbers.
+pub fn lines<R: BufRead>(reader: R) -> Lines<R> {
+    Lines {
+        reader,
+        line: 0,
+        failed: false,
+    }
+}
+
+fn parse_line<T>(day: u32, line: usize, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    text.parse()
+        .map_err(|e| ParseError::new(line, 1, text, format!("invalid value ({})", e)).with_day(day))
+}
+
+/// Lines without the blank lines at the end, see [`parse_lines`].
+struct ContentLines<R> {
+    lines: Lines<R>,
+    /// Blank lines read so far, dropped unless more content follows.
+    blank: VecDeque<(usize, String)>,
+    /// Lines ready to be returned.
+    ready: VecDeque<(usize, String)>,
+}
+
+impl<R: BufRead> Iterator for ContentLines<R> {
+    type Item = Result<(usize, String), ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.ready.is_empty() {
+            let (number, text) = match self.lines.next()? {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err)),
+            };
+            if text.trim().is_empty() {
+                self.blank.push_back((number, text));
+            } else {
+                self.ready.append(&mut self.blank);
+                self.ready.push_back((number, text));
+            }
+        }
+        self.ready.pop_front().map(Ok)
+    }
+}
+
+/// Parses every line with [`FromStr`], errors point at the line that failed.
+/// Blank lines at the end are skipped, blank lines between values are parsed
+/// like any other.
+pub fn parse_lines<T, R>(day: u32, reader: R) -> impl Iterator<Item = Result<T, ReadError>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+    R: BufRead,
+{
+    let lines = ContentLines {
+        lines: lines(reader),
+        blank: VecDeque::new(),
+        ready: VecDeque::new(),
+    };
+    lines.map(move |line| {
+        let (number, text) = line?;
+        Ok(parse_line(day, number, &text)?)
+    })
+}
+
+/// Parses every line of the file, see [`parse_lines`].
+pub fn read_lines<T, P>(day: u32, path: P) -> Result<Vec<T>, ReadError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+    P: AsRef<Path>,
+{
+    parse_lines(day, open(path)?).collect()
+}
+
+/// Group of consecutive non-blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// Day passed to [`blocks`].
+    pub day: u32,
+    /// Number of the first line of the block.
+    pub line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Block {
+    /// Parses every line of the block, see [`parse_lines`].
+    pub fn parse<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, text)| parse_line(self.day, self.line + i, text))
+            .collect()
+    }
+
+    /// Lines of the block joined by `\n`, for parsing the block as a whole
+    /// with a [`Parser`](crate::parse::Parser).
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Moves error reported relative to [`Block::text`] to its position in
+    /// the input.
+    pub fn within<T>(&self, res: Result<T, ParseError>) -> Result<T, ParseError> {
+        res.map_err(|err| ParseError {
+            day: self.day,
+            line: self.line + err.line - 1,
+            ..err
+        })
+    }
+}
+
+/// Iterator over blocks of lines, see [`blocks`].
+pub struct Blocks<R> {
+    day: u32,
+    lines: Lines<R>,
+}
+
+impl<R: BufRead> Iterator for Blocks<R> {
+    type Item = Result<Block, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut block = Block {
+            day: self.day,
+            line: 0,
+            lines: Vec::new(),
+        };
+        for line in self.lines.by_ref() {
+            let (number, text) = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err)),
+            };
+            match (text.trim().is_empty(), block.lines.is_empty()) {
+                (true, true) => continue,
+                (true, false) => break,
+                (false, _) => {
+                    if block.lines.is_empty() {
+                        block.line = number;
+                    }
+                    block.lines.push(text);
+                }
+            }
+        }
+        (!block.lines.is_empty()).then_some(Ok(block))
+    }
+}
+
+/// Blocks of lines separated by one or more blank lines, which is how puzzles
+/// usually group their input.
+pub fn blocks<R: BufRead>(day: u32, reader: R) -> Blocks<R> {
+    Blocks {
+        day,
+        lines: lines(reader),
+    }
+}
+
+/// Blocks of input that is already in memory, see [`blocks`].
+pub fn str_blocks(day: u32, input: &str) -> impl Iterator<Item = Block> + '_ {
+    // Reading valid UTF-8 from memory cannot fail.
+    blocks(day, input.as_bytes()).map(|block| block.expect("failed to read str"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::ErrorKind;
+
+    use super::{blocks, lines, parse_lines, read_lines, str_blocks, Block, ReadError};
+    use crate::parse::Parser;
+    use crate::util::{temp_file_with_content, windows_input};
+
+    #[test]
+    fn test_lines() {
+        let read: Vec<_> = lines(windows_input("a\nb").as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            read,
+            vec![
+                (1, "a".to_string()),
+                (2, "b".to_string()),
+                (3, String::new())
+            ]
+        );
+        assert_eq!(lines(&b""[..]).count(), 0);
+        assert_eq!(lines(&b"x"[..]).next().unwrap().unwrap(), (1, "x".into()));
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let mut read = lines(&b"ok\n\xff\nnever"[..]);
+        assert!(read.next().unwrap().is_ok());
+        match read.next().unwrap() {
+            Err(ReadError::Io { line, source }) => {
+                assert_eq!(line, 2);
+                assert_eq!(source.kind(), ErrorKind::InvalidData);
+            }
+            other => panic!("expected I/O error, got {:?}", other),
+        }
+        assert!(read.next().is_none());
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let numbers: Result<Vec<u32>, _> = parse_lines(0, "1\n2\n3".as_bytes()).collect();
+        assert_eq!(numbers.unwrap(), vec![1, 2, 3]);
+
+        let err = parse_lines::<u32, _>(0, "1\n2x\n3".as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        match &err {
+            ReadError::Parse(err) => {
+                assert_eq!((err.line, err.column), (2, 1));
+                assert_eq!(err.text, "2x");
+            }
+            other => panic!("expected parse error, got {:?}", other),
+        }
+        assert!(err
+            .to_string()
+            .starts_with("line 2, column 1: invalid value"));
+
+        let numbers: Result<Vec<u32>, _> = parse_lines(0, "1\n2\n\n \n".as_bytes()).collect();
+        assert_eq!(numbers.unwrap(), vec![1, 2]);
+        let err = parse_lines::<u32, _>(0, "1\n\n3\n\n".as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert!(matches!(err, ReadError::Parse(err) if err.line == 2));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "1000\n2000\n\n4000\n\n\n  \n5000\n6000\n\n";
+        let read: Vec<Block> = blocks(0, input.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            read.iter().map(|b| b.line).collect::<Vec<_>>(),
+            vec![1, 4, 8]
+        );
+        let sums: Vec<u32> = read
+            .iter()
+            .map(|b| b.parse::<u32>().unwrap().iter().sum())
+            .collect();
+        assert_eq!(sums, vec![3000, 4000, 11000]);
+        assert_eq!(blocks(0, "\n\n".as_bytes()).count(), 0);
+
+        let block = blocks(1, "1\n\n2\nx".as_bytes()).nth(1).unwrap().unwrap();
+        let err = block.parse::<u32>().unwrap_err();
+        assert_eq!((err.day, err.line), (1, 4));
+    }
+
+    #[test]
+    fn test_block_within() {
+        let input = "a\n\nb 1\nb x\n";
+        let block = str_blocks(5, input).nth(1).unwrap();
+        assert_eq!(block.text(), "b 1\nb x");
+
+        let text = block.text();
+        let p = Parser::new(0, &text);
+        let err = block.within(p.parse::<u32>(&text[6..])).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (5, 4, 3));
+    }
+
+    #[test]
+    fn test_read_lines() {
+        let path = temp_file_with_content("aoc_2022_read_lines.txt", "\u{feff}7\r\n-8\r\n");
+        assert_eq!(read_lines::<i32, _>(0, &path).unwrap(), vec![7, -8]);
+        let path = temp_file_with_content("aoc_2022_read_lines_blank.txt", "1\n2\n\n");
+        assert_eq!(read_lines::<i32, _>(0, &path).unwrap(), vec![1, 2]);
+
+        let err = read_lines::<i32, _>(0, "does/not/exist.txt").unwrap_err();
+        assert!(matches!(err, ReadError::Open { .. }));
+        assert!(err
+            .to_string()
+            .starts_with("failed to open does/not/exist.txt"));
+    }
+}
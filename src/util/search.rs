@@ -0,0 +1,317 @@
+//! Shortest path searches over graphs given by a neighbour function.
+//!
+//! Every search starts from any number of nodes and stops at the first node
+//! accepted by `goal`; pass `|_| false` to reach the whole graph. The returned
+//! [`Search`] keeps distances and predecessors of all reached nodes, so paths
+//! to them can be reconstructed afterwards. Searches are cancellation points,
+//! see [`cancel::check`].
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::cancel::{self, Cancelled};
+
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    /// Cost of the cheapest path found to every reached node.
+    pub dist: HashMap<N, C>,
+    /// Previous node on that path, start nodes have none.
+    pub prev: HashMap<N, N>,
+    /// First node accepted by `goal`.
+    pub goal: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Search {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// Nodes from one of the starts to `to`, both included.
+    pub fn path(&self, to: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(to) {
+            return None;
+        }
+        let mut path = vec![to.clone()];
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth first search, the distance is the number of steps.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Result<Search<N, usize>, Cancelled>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.dist.contains_key(&start) {
+            search.dist.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        cancel::check()?;
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let steps = search.dist[&node] + 1;
+        for next in neighbours(&node) {
+            if !search.dist.contains_key(&next) {
+                search.dist.insert(next.clone(), steps);
+                search.prev.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Ok(search)
+}
+
+/// Dijkstra's search, `neighbours` returns nodes with the cost of getting to
+/// them, which must not be negative.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Result<Search<N, C>, Cancelled>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), goal)
+}
+
+/// A* search, `heuristic` estimates the remaining cost to the goal. The found
+/// path is the cheapest one as long as the heuristic never overestimates.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Result<Search<N, C>, Cancelled>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    let mut open = BinaryHeap::new();
+    for start in starts {
+        search.dist.insert(start.clone(), C::default());
+        open.push(Open {
+            estimate: heuristic(&start),
+            cost: C::default(),
+            node: start,
+        });
+    }
+
+    while let Some(Open { cost, node, .. }) = open.pop() {
+        // A cheaper path to the node was found after this one was queued.
+        if cost > search.dist[&node] {
+            continue;
+        }
+        cancel::check()?;
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if search.dist.get(&next).is_none_or(|c| next_cost < *c) {
+                search.dist.insert(next.clone(), next_cost);
+                search.prev.insert(next.clone(), node.clone());
+                open.push(Open {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    Ok(search)
+}
+
+/// Node waiting in the open set, ordered so that `BinaryHeap` pops the lowest
+/// estimate first.
+struct Open<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Open<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Open<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Open<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for Open<N, C> {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    use super::{astar, bfs, dijkstra};
+    use crate::cancel::{self, Cancelled};
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{parse_to_digit_grid, Grid};
+
+    /// Weighted graph 0 -> 1 -> 2 -> 3 with a costly shortcut 0 -> 3 and an
+    /// unreachable node 4.
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            4 => vec![(0, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let unweighted = |n: &u32| edges(n).into_iter().map(|(n, _)| n);
+        let search = bfs([0], unweighted, |n| *n == 3).unwrap();
+        assert_eq!(search.goal, Some(3));
+        assert_eq!(search.goal_distance(), Some(1));
+        assert_eq!(search.goal_path(), Some(vec![0, 3]));
+
+        let search = bfs([1, 0], unweighted, |_| false).unwrap();
+        assert_eq!(search.goal, None);
+        assert_eq!(search.distance(&2), Some(1));
+        assert_eq!(search.path(&3), Some(vec![0, 3]));
+        assert_eq!(search.path(&0), Some(vec![0]));
+        assert_eq!(search.path(&4), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra([0], edges, |n| *n == 3).unwrap();
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec![0, 1, 2, 3]));
+
+        let search = dijkstra([0], edges, |n| *n == 4).unwrap();
+        assert_eq!(search.goal, None);
+        assert_eq!(search.goal_path(), None);
+        assert_eq!(search.dist.len(), 4);
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let token = Arc::new(AtomicBool::new(true));
+        cancel::with_token(token, || {
+            assert_eq!(bfs([0], |_| [], |_| false).unwrap_err(), Cancelled);
+            assert_eq!(dijkstra([0], edges, |_| false).unwrap_err(), Cancelled);
+        });
+    }
+
+    /// Grid of costs of entering each square.
+    fn generate(rng: &mut Rng) -> String {
+        let (rows, cols) = (rng.range(1, 8), rng.range(1, 8));
+        let lines: Vec<String> = (0..rows)
+            .map(|_| (0..cols).map(|_| rng.range(1, 9).to_string()).collect())
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Cheapest cost from the top left to every square, relaxing all edges
+    /// until nothing changes.
+    fn reference(grid: &Grid<u32>) -> Grid<u32> {
+        let mut cost = Grid::new(grid.width(), grid.height(), u32::MAX);
+        cost[(0, 0)] = 0;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for pos in grid.positions() {
+                for next in grid.neighbours_4(pos) {
+                    if cost[pos] != u32::MAX && cost[pos] + grid[next] < cost[next] {
+                        cost[next] = cost[pos] + grid[next];
+                        changed = true;
+                    }
+                }
+            }
+        }
+        cost
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            let grid = parse_to_digit_grid(input).unwrap();
+            let expected = reference(&grid);
+            let end = (grid.width() - 1, grid.height() - 1);
+            let neighbours = |pos: &(usize, usize)| {
+                let grid = &grid;
+                grid.neighbours_4(*pos).map(move |next| (next, grid[next]))
+            };
+
+            let all = dijkstra([(0, 0)], neighbours, |_| false).unwrap();
+            for pos in grid.positions() {
+                assert_eq!(all.distance(&pos), Some(expected[pos]));
+            }
+
+            let manhattan = |pos: &(usize, usize)| (end.0 - pos.0 + end.1 - pos.1) as u32;
+            let search = astar([(0, 0)], neighbours, manhattan, |p| *p == end).unwrap();
+            assert_eq!(search.goal_distance(), Some(expected[end]));
+
+            // The path is made of steps between neighbours adding up to its
+            // cost.
+            let path = search.goal_path().unwrap();
+            assert_eq!(path.first(), Some(&(0, 0)));
+            let cost: u32 = path
+                .windows(2)
+                .map(|w| {
+                    assert!(grid.neighbours_4(w[0]).any(|n| n == w[1]));
+                    grid[w[1]]
+                })
+                .sum();
+            assert_eq!(cost, expected[end]);
+        });
+    }
+}
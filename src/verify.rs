@@ -0,0 +1,223 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::input::InputSource;
+use crate::runner::{self, RunResult, Status};
+use crate::solutions::Solution;
+
+pub const DEFAULT_OUTPUTS_DIR: &str = "outputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// There is no stored answer to compare with.
+    Missing,
+    /// Answer was missing and has been written to outputs.
+    Recorded,
+    /// Solution did not produce an answer.
+    Error(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL expected: {}, actual: {}", expected, actual)
+            }
+            Verdict::Missing => write!(f, "missing"),
+            Verdict::Recorded => write!(f, "recorded"),
+            Verdict::Error(err) => write!(f, "error: {}", err),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub result: RunResult,
+    pub verdict: Verdict,
+}
+
+pub fn output_path<P: AsRef<Path>>(dir: P, day: u32, part: u32) -> PathBuf {
+    dir.as_ref().join(format!("{:02}.{}.txt", day, part))
+}
+
+/// Reads stored answer, `None` if it was not recorded yet or the file is an
+/// empty placeholder.
+pub fn read_answer<P: AsRef<Path>>(path: P) -> anyhow::Result<Option<String>> {
+    let path = path.as_ref();
+    match fs::read_to_string(path) {
+        Ok(answer) if answer.trim().is_empty() => Ok(None),
+        Ok(answer) => Ok(Some(answer)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Compares answer with the stored one. Trailing whitespace is ignored so
+/// answer files can end with a newline.
+pub fn check_answer(expected: Option<&str>, actual: &str) -> Verdict {
+    match expected {
+        None => Verdict::Missing,
+        Some(expected) if expected.trim_end() == actual.trim_end() => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.trim_end().to_string(),
+            actual: actual.trim_end().to_string(),
+        },
+    }
+}
+
+/// Runs the solutions and compares their answers with `outputs_dir`. With
+/// `record` missing answers are written to `outputs_dir`, existing ones are
+/// never overwritten.
+pub fn verify<P: AsRef<Path>>(
+    solutions: &[&Solution],
+    source: &InputSource,
+    outputs_dir: P,
+    record: bool,
+) -> Vec<Verification> {
+    let outputs_dir = outputs_dir.as_ref();
+
+    runner::run_all(solutions, source)
+        .into_iter()
+        .map(|result| {
+            let verdict = match (&result.status, &result.answer) {
+                (Status::Ok, Some(answer)) => {
+                    verify_answer(outputs_dir, result.day, result.part, answer, record)
+                }
+                (Status::Failed(err), _) => Verdict::Error(err.clone()),
+                (status, _) => Verdict::Error(status.to_string()),
+            };
+            Verification { result, verdict }
+        })
+        .collect()
+}
+
+fn verify_answer(outputs_dir: &Path, day: u32, part: u32, answer: &str, record: bool) -> Verdict {
+    let path = output_path(outputs_dir, day, part);
+    let expected = match read_answer(&path) {
+        Ok(expected) => expected,
+        Err(err) => return Verdict::Error(format!("{:#}", err)),
+    };
+
+    match check_answer(expected.as_deref(), answer) {
+        Verdict::Missing if record => match write_answer(&path, answer) {
+            Ok(()) => Verdict::Recorded,
+            Err(err) => Verdict::Error(format!("{:#}", err)),
+        },
+        verdict => verdict,
+    }
+}
+
+fn write_answer(path: &Path, answer: &str) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    fs::write(path, answer).with_context(|| format!("failed to write {}", path.display()))
+}
+
+pub fn print_verifications(verifications: &[Verification]) {
+    println!("{:>3}  {:>4}  {:>10}  Verdict", "Day", "Part", "Time");
+    for v in verifications {
+        println!(
+            "{:>3}  {:>4}  {:>10}  {}",
+            v.result.day,
+            v.result.part,
+            runner::format_duration(v.result.duration),
+            v.verdict
+        );
+    }
+
+    let count = |f: fn(&Verdict) -> bool| verifications.iter().filter(|v| f(&v.verdict)).count();
+    println!(
+        "{} passed, {} failed, {} missing, {} recorded, {} errors",
+        count(|v| *v == Verdict::Pass),
+        count(|v| matches!(v, Verdict::Fail { .. })),
+        count(|v| *v == Verdict::Missing),
+        count(|v| *v == Verdict::Recorded),
+        count(|v| matches!(v, Verdict::Error(_))),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use std::env::temp_dir;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::{check_answer, output_path, read_answer, verify, Verdict};
+    use crate::input::InputSource;
+    use crate::solutions::find;
+    use crate::util::temp_file_with_content;
+
+    #[test]
+    fn test_output_path() {
+        assert_eq!(
+            output_path("outputs", 5, 2),
+            PathBuf::from("outputs/05.2.txt")
+        );
+    }
+
+    #[test]
+    fn test_check_answer() {
+        assert_eq!(check_answer(Some("15"), "15"), Verdict::Pass);
+        assert_eq!(check_answer(Some("15\n"), "15"), Verdict::Pass);
+        assert_eq!(check_answer(None, "15"), Verdict::Missing);
+
+        let placeholder = temp_file_with_content("aoc_2022_verify_empty.txt", "\n");
+        assert_eq!(read_answer(placeholder).unwrap(), None);
+        assert_eq!(
+            check_answer(Some("16"), "15"),
+            Verdict::Fail {
+                expected: "16".to_string(),
+                actual: "15".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_and_record() {
+        let input = temp_file_with_content("aoc_2022_verify_input.txt", "A Y\nB X\nC Z");
+        let source = InputSource::File(input);
+
+        let mut outputs = temp_dir();
+        outputs.push("aoc_2022_verify_outputs");
+        let _ = fs::remove_dir_all(&outputs);
+
+        let solutions = [find(2022, 2, 1).unwrap(), find(2022, 2, 2).unwrap()];
+
+        let res = verify(&solutions, &source, &outputs, false);
+        assert!(res.iter().all(|v| v.verdict == Verdict::Missing));
+
+        let res = verify(&solutions, &source, &outputs, true);
+        assert!(res.iter().all(|v| v.verdict == Verdict::Recorded));
+        assert_eq!(
+            read_answer(output_path(&outputs, 2, 2)).unwrap().as_deref(),
+            Some("12")
+        );
+
+        fs::write(output_path(&outputs, 2, 1), "16").unwrap();
+        let res = verify(&solutions, &source, &outputs, true);
+        assert_eq!(
+            res[0].verdict,
+            Verdict::Fail {
+                expected: "16".to_string(),
+                actual: "15".to_string()
+            }
+        );
+        assert_eq!(res[1].verdict, Verdict::Pass);
+        // Existing answers are never overwritten.
+        assert_eq!(
+            read_answer(output_path(&outputs, 2, 1)).unwrap().as_deref(),
+            Some("16")
+        );
+    }
+}
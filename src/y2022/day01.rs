@@ -0,0 +1,133 @@
+use crate::parse::ParseError;
+use crate::solutions::Example;
+use crate::util::read;
+
+const DAY: u32 = 1;
+
+/// Sums calories carried by each elf.
+pub fn parse_elves(input: &str) -> Result<Vec<u64>, ParseError> {
+    read::str_blocks(DAY, input)
+        .map(|elf| Ok(elf.parse::<u64>()?.iter().sum()))
+        .collect()
+}
+
+pub fn day_1_1(input: &str) -> anyhow::Result<u64> {
+    parse_elves(input)?
+        .into_iter()
+        .max()
+        .ok_or(anyhow::anyhow!("failed to get max value"))
+}
+
+pub fn day_1_2(input: &str) -> anyhow::Result<u64> {
+    let mut sums = parse_elves(input)?;
+
+    sums.sort_unstable();
+
+    Ok(sums.iter().rev().take(3).sum())
+}
+
+const INPUT: &str = r"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("24000"), Some("45000")],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::day_1_1;
+    use super::day_1_2;
+    use super::INPUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_day_1_1() {
+        assert_eq!(day_1_1(INPUT).expect("failed to run 1.1"), 24000);
+    }
+
+    #[test]
+    fn test_day_1_2() {
+        assert_eq!(day_1_2(INPUT).expect("failed to run 1.2"), 45000);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = day_1_1("1000\n\n20x0")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (1, 3, 1));
+        assert_eq!(err.text, "20x0");
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(day_1_1(&input).expect("failed to run 1"), 24000);
+        assert_eq!(day_1_2(&input).expect("failed to run 2"), 45000);
+    }
+
+    /// Random elves carrying 1 to 5 items each.
+    fn generate(rng: &mut Rng) -> String {
+        let elves: Vec<String> = (0..rng.range(1, 8))
+            .map(|_| {
+                let items: Vec<String> = (0..rng.range(1, 5))
+                    .map(|_| rng.range(1, 99999).to_string())
+                    .collect();
+                items.join("\n")
+            })
+            .collect();
+        elves.join("\n\n")
+    }
+
+    fn reference_sums(input: &str) -> Vec<u64> {
+        input
+            .split("\n\n")
+            .map(|elf| elf.lines().map(|l| l.parse::<u64>().unwrap()).sum())
+            .collect()
+    }
+
+    /// Best sum over every choice of up to three different elves.
+    fn reference_top_3(input: &str) -> u64 {
+        let sums = reference_sums(input);
+        let n = sums.len();
+        let mut best = 0;
+        for a in 0..n {
+            for b in a..n {
+                for c in b..n {
+                    let mut chosen = vec![a, b, c];
+                    chosen.dedup();
+                    if chosen.len() == n.min(3) {
+                        best = best.max(chosen.iter().map(|&i| sums[i]).sum());
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            let max = reference_sums(input).into_iter().max().unwrap();
+            assert_eq!(day_1_1(input).unwrap(), max);
+            assert_eq!(day_1_2(input).unwrap(), reference_top_3(input));
+        });
+    }
+}
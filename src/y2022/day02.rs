@@ -0,0 +1,223 @@
+use std::str::FromStr;
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+
+const DAY: u32 = 2;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u64)]
+pub enum GameMove {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u64)]
+pub enum GameResult {
+    Loss = 0,
+    Draw = 3,
+    Win = 6,
+}
+
+impl GameMove {
+    pub fn game_result(self, other: GameMove) -> GameResult {
+        if self == other {
+            return GameResult::Draw;
+        }
+
+        match (self, other) {
+            (GameMove::Rock, GameMove::Paper) => GameResult::Loss,
+            (GameMove::Rock, GameMove::Scissors) => GameResult::Win,
+            (GameMove::Paper, GameMove::Rock) => GameResult::Win,
+            (GameMove::Paper, GameMove::Scissors) => GameResult::Loss,
+            (GameMove::Scissors, GameMove::Rock) => GameResult::Loss,
+            (GameMove::Scissors, GameMove::Paper) => GameResult::Win,
+            _ => panic!("unexpected game!"),
+        }
+    }
+
+    pub fn move_for_result(self, res: GameResult) -> GameMove {
+        if res == GameResult::Draw {
+            return self;
+        }
+
+        match (self, res) {
+            (GameMove::Rock, GameResult::Loss) => GameMove::Scissors,
+            (GameMove::Rock, GameResult::Win) => GameMove::Paper,
+            (GameMove::Paper, GameResult::Loss) => GameMove::Rock,
+            (GameMove::Paper, GameResult::Win) => GameMove::Scissors,
+            (GameMove::Scissors, GameResult::Loss) => GameMove::Paper,
+            (GameMove::Scissors, GameResult::Win) => GameMove::Rock,
+            (m, r) => panic!("unexpected move + result combination: {:?} - {:?}", m, r),
+        }
+    }
+}
+
+impl FromStr for GameMove {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "A" | "X" => GameMove::Rock,
+            "B" | "Y" => GameMove::Paper,
+            "C" | "Z" => GameMove::Scissors,
+            s => return Err(Parser::new(DAY, s).error(s, "unexpected move")),
+        })
+    }
+}
+
+impl FromStr for GameResult {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "X" => GameResult::Loss,
+            "Y" => GameResult::Draw,
+            "Z" => GameResult::Win,
+            s => return Err(Parser::new(DAY, s).error(s, "unexpected result")),
+        })
+    }
+}
+
+fn parse_games<A, B>(input: &str) -> Result<Vec<(A, B)>, ParseError>
+where
+    A: FromStr<Err = ParseError>,
+    B: FromStr<Err = ParseError>,
+{
+    let p = Parser::new(DAY, input);
+
+    input
+        .split("\n")
+        .map(|line| {
+            let (m1, m2) = p.split_once(line, " ")?;
+            Ok((p.within(m1, m1.parse())?, p.within(m2, m2.parse())?))
+        })
+        .collect()
+}
+
+pub fn day_2_1(input: &str) -> anyhow::Result<u64> {
+    let games: Vec<(GameMove, GameMove)> = parse_games(input)?;
+
+    let score = games
+        .iter()
+        .map(|(m1, m2)| m2.game_result(*m1) as u64 + *m2 as u64)
+        .sum();
+
+    Ok(score)
+}
+
+pub fn day_2_2(input: &str) -> anyhow::Result<u64> {
+    let games: Vec<(GameMove, GameResult)> = parse_games(input)?;
+
+    let score = games
+        .iter()
+        .map(|(m1, res)| {
+            let m2 = m1.move_for_result(*res);
+            m2 as u64 + *res as u64
+        })
+        .sum();
+
+    Ok(score)
+}
+
+const INPUT: &str = r"A Y
+B X
+C Z";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("15"), Some("12")],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::day_2_1;
+    use super::day_2_2;
+    use super::INPUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(day_2_1(INPUT).expect("failed to run 1"), 15);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(day_2_2(INPUT).expect("failed to run 2"), 12);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = day_2_2("A Y\nB W")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (2, 2, 3));
+        assert_eq!(err.text, "W");
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(day_2_1(&input).expect("failed to run 1"), 15);
+        assert_eq!(day_2_2(&input).expect("failed to run 2"), 12);
+    }
+
+    fn generate(rng: &mut Rng) -> String {
+        let games: Vec<String> = (0..rng.range(1, 30))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.choose(&["A", "B", "C"]),
+                    rng.choose(&["X", "Y", "Z"])
+                )
+            })
+            .collect();
+        games.join("\n")
+    }
+
+    fn shapes(line: &str) -> (u64, u64) {
+        let b = line.as_bytes();
+        ((b[0] - b'A') as u64, (b[2] - b'X') as u64)
+    }
+
+    /// 0 for loss, 1 for draw, 2 for win of `me` against `other`.
+    fn outcome(other: u64, me: u64) -> u64 {
+        (me + 4 - other) % 3
+    }
+
+    fn reference_1(input: &str) -> u64 {
+        input
+            .lines()
+            .map(|line| {
+                let (other, me) = shapes(line);
+                me + 1 + outcome(other, me) * 3
+            })
+            .sum()
+    }
+
+    /// Tries every shape until the wanted outcome is found.
+    fn reference_2(input: &str) -> u64 {
+        input
+            .lines()
+            .map(|line| {
+                let (other, wanted) = shapes(line);
+                let me = (0..3).find(|&me| outcome(other, me) == wanted).unwrap();
+                me + 1 + wanted * 3
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(day_2_1(input).unwrap(), reference_1(input));
+            assert_eq!(day_2_2(input).unwrap(), reference_2(input));
+        });
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+
+const DAY: u32 = 3;
+
+fn parse_rucksacks(input: &str) -> Result<Vec<&str>, ParseError> {
+    let p = Parser::new(DAY, input);
+
+    input
+        .split("\n")
+        .filter(|l| !l.is_empty())
+        .map(
+            |line| match line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                Some((i, _)) => Err(p.error(&line[i..], "expected item letter")),
+                None => Ok(line),
+            },
+        )
+        .collect()
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<u64> {
+    let p = Parser::new(DAY, input);
+
+    let priority_sum = parse_rucksacks(input)?
+        .into_iter()
+        .map(|line| {
+            if !line.len().is_multiple_of(2) {
+                return Err(p.error(line, "expected even number of items"));
+            }
+            let c = find_item_in_both_parts(line)
+                .ok_or_else(|| p.error(line, "no item in both compartments"))?;
+            Ok(get_item_priority(c))
+        })
+        .sum::<Result<u64, _>>()?;
+
+    Ok(priority_sum)
+}
+
+pub fn find_item_in_both_parts(s: &str) -> Option<char> {
+    let p1 = &s[..s.len() / 2];
+    let p2 = &s[s.len() / 2..];
+
+    let mut elems = HashSet::new();
+    for c in p1.chars() {
+        elems.insert(c);
+    }
+
+    p2.chars().find(|c| elems.contains(c))
+}
+
+pub fn get_item_priority(item: char) -> u64 {
+    if item.is_lowercase() {
+        (item as u64) - 96
+    } else {
+        (item as u64) - 64 + 26
+    }
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<u64> {
+    let p = Parser::new(DAY, input);
+    let rucksacks = parse_rucksacks(input)?;
+
+    let sum = rucksacks
+        .chunks(3)
+        .map(|group| {
+            if group.len() != 3 {
+                return Err(p.error(group[0], "expected groups of 3 rucksacks"));
+            }
+            let id = find_id_in_group(group)
+                .ok_or_else(|| p.error(group[0], "no item shared by the group"))?;
+            Ok(get_item_priority(id))
+        })
+        .sum::<Result<u64, _>>()?;
+
+    Ok(sum)
+}
+
+pub fn find_id_in_group(group: &[&str]) -> Option<char> {
+    let mut items: HashMap<char, usize> = HashMap::new();
+    for (i, sack) in group.iter().enumerate() {
+        for c in sack.chars() {
+            let entry = items.entry(c).or_insert(0);
+            if *entry == i {
+                *entry += 1;
+            }
+        }
+    }
+    items
+        .into_iter()
+        .find(|(_, v)| *v == group.len())
+        .map(|(k, _)| k)
+}
+
+const INPUT: &str = r"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("157"), Some("70")],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 157);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 70);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1("abca\nab1b")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (3, 2, 3));
+
+        let err = task_1("abca\nabc")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (2, 1));
+
+        assert!(task_2("abc\nade").is_err());
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 157);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 70);
+    }
+
+    const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    /// Rucksack with exactly one item type in both compartments, `badge` is
+    /// put into one of them and `excluded` items are not used at all.
+    fn rucksack(rng: &mut Rng, badge: char, excluded: &[char]) -> String {
+        let mut pool: Vec<char> = ITEMS
+            .chars()
+            .filter(|c| *c != badge && !excluded.contains(c))
+            .collect();
+        rng.shuffle(&mut pool);
+        let shared = pool.pop().unwrap();
+        let (left, right) = pool.split_at(pool.len() / 2);
+
+        let size = rng.range(2, 8) as usize;
+        let mut halves = [vec![shared], vec![shared]];
+        for _ in 1..size {
+            halves[0].push(*rng.choose(left));
+            halves[1].push(*rng.choose(right));
+        }
+        let half = rng.below(2);
+        halves[half][rng.range(1, size as i64 - 1) as usize] = badge;
+        for h in halves.iter_mut() {
+            rng.shuffle(h);
+        }
+        halves.concat().into_iter().collect()
+    }
+
+    fn generate(rng: &mut Rng) -> String {
+        let mut lines = Vec::new();
+        for _ in 0..rng.range(1, 5) {
+            let badge = *rng.choose(&ITEMS.chars().collect::<Vec<_>>());
+            let first = rucksack(rng, badge, &[]);
+            let second = rucksack(rng, badge, &[]);
+            let in_both: Vec<char> = first.chars().filter(|c| second.contains(*c)).collect();
+            let third = rucksack(rng, badge, &in_both);
+            lines.extend([first, second, third]);
+        }
+        lines.join("\n")
+    }
+
+    fn priority(item: char) -> u64 {
+        ITEMS.find(item).unwrap() as u64 + 1
+    }
+
+    fn reference_1(input: &str) -> u64 {
+        input
+            .lines()
+            .map(|line| {
+                let (left, right) = line.split_at(line.len() / 2);
+                let item = left.chars().find(|c| right.contains(*c)).unwrap();
+                priority(item)
+            })
+            .sum()
+    }
+
+    fn reference_2(input: &str) -> u64 {
+        let lines: Vec<&str> = input.lines().collect();
+        lines
+            .chunks(3)
+            .map(|group| {
+                let badge = ITEMS
+                    .chars()
+                    .find(|c| group.iter().all(|sack| sack.contains(*c)))
+                    .unwrap();
+                priority(badge)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference_1(input));
+            assert_eq!(task_2(input).unwrap(), reference_2(input));
+        });
+    }
+}
@@ -0,0 +1,179 @@
+use std::ops::RangeInclusive;
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::util::IntervalSet;
+
+const DAY: u32 = 4;
+
+pub type Pair = (RangeInclusive<isize>, RangeInclusive<isize>);
+
+pub fn str_to_ranges(s: &str) -> Result<Pair, ParseError> {
+    let p = Parser::new(DAY, s);
+    let (r1, r2) = p.split_once(s, ",")?;
+    Ok((
+        p.within(r1, str_to_range(r1))?,
+        p.within(r2, str_to_range(r2))?,
+    ))
+}
+
+pub fn str_to_range(s: &str) -> Result<RangeInclusive<isize>, ParseError> {
+    let p = Parser::new(DAY, s);
+    let (start, end) = p.split_once(s, "-")?;
+    let (start, end): (isize, isize) = (p.parse(start)?, p.parse(end)?);
+    if start > end {
+        return Err(p.error(s, "range start is after its end"));
+    }
+    Ok(start..=end)
+}
+
+fn parse_pairs(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let p = Parser::new(DAY, input);
+
+    input
+        .split("\n")
+        .map(|line| p.within(line, str_to_ranges(line)))
+        .collect()
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<u64> {
+    let sum = parse_pairs(input)?
+        .into_iter()
+        .map(|(r1, r2)| {
+            // Only the longer range can contain the other one.
+            let (outer, inner) = match r1.end() - r1.start() >= r2.end() - r2.start() {
+                true => (r1, r2),
+                false => (r2, r1),
+            };
+            if IntervalSet::from(outer).contains_range(inner) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    Ok(sum)
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<u64> {
+    let sum = parse_pairs(input)?
+        .into_iter()
+        .map(|(r1, r2)| {
+            if IntervalSet::from(r1).overlaps(r2) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    Ok(sum)
+}
+
+const INPUT: &str = r"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("2"), Some("4")],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 2);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 4);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1("2-4,6-8\n2-3,4:5")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (4, 2, 5));
+        assert_eq!(err.text, "4:5");
+
+        let err = task_2("2-4,8-6")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.message, "range start is after its end");
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 2);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 4);
+    }
+
+    fn range(rng: &mut Rng) -> String {
+        let start = rng.range(1, 20);
+        format!("{}-{}", start, rng.range(start, 20))
+    }
+
+    fn generate(rng: &mut Rng) -> String {
+        let pairs: Vec<String> = (0..rng.range(1, 30))
+            .map(|_| format!("{},{}", range(rng), range(rng)))
+            .collect();
+        pairs.join("\n")
+    }
+
+    /// Sections of both elves of every pair.
+    fn sections(input: &str) -> Vec<[Vec<u64>; 2]> {
+        let sections = |r: &str| {
+            let (a, b) = r.split_once('-').unwrap();
+            (a.parse().unwrap()..=b.parse().unwrap()).collect()
+        };
+        input
+            .lines()
+            .map(|line| {
+                let (a, b) = line.split_once(',').unwrap();
+                [sections(a), sections(b)]
+            })
+            .collect()
+    }
+
+    fn reference_1(input: &str) -> u64 {
+        let covers = |a: &Vec<u64>, b: &Vec<u64>| b.iter().all(|s| a.contains(s));
+        sections(input)
+            .iter()
+            .filter(|[a, b]| covers(a, b) || covers(b, a))
+            .count() as u64
+    }
+
+    fn reference_2(input: &str) -> u64 {
+        sections(input)
+            .iter()
+            .filter(|[a, b]| a.iter().any(|s| b.contains(s)))
+            .count() as u64
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference_1(input));
+            assert_eq!(task_2(input).unwrap(), reference_2(input));
+        });
+    }
+}
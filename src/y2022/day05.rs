@@ -0,0 +1,291 @@
+use std::collections::VecDeque;
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::util::read::{self, Block};
+
+const DAY: u32 = 5;
+
+pub fn parse_stacks(s: &str) -> Result<Vec<VecDeque<char>>, ParseError> {
+    let p = Parser::new(DAY, s);
+    let lines: Vec<&str> = s.split("\n").collect();
+
+    let mut stacks = vec![VecDeque::new(); lines[0].len() / 4 + 1];
+
+    for line in lines.iter().take(lines.len() - 1) {
+        for (i, (pos, c)) in line.char_indices().skip(1).step_by(4).enumerate() {
+            if c == ' ' {
+                continue;
+            }
+            if i >= stacks.len() {
+                return Err(p.error(&line[pos..], "crate outside of stacks"));
+            }
+            stacks[i].push_front(c);
+        }
+    }
+
+    Ok(stacks)
+}
+
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Move {
+    pub fn apply_9000(&self, stacks: &mut [VecDeque<char>]) -> anyhow::Result<()> {
+        for _c in 0..self.count {
+            let e = self.take_crate(stacks)?;
+            stacks[self.to - 1].push_back(e);
+        }
+        Ok(())
+    }
+
+    pub fn apply_9001(&self, stacks: &mut [VecDeque<char>]) -> anyhow::Result<()> {
+        let mut transfer = VecDeque::with_capacity(self.count);
+
+        for _ in 0..self.count {
+            transfer.push_front(self.take_crate(stacks)?);
+        }
+        for t in transfer {
+            stacks[self.to - 1].push_back(t);
+        }
+        Ok(())
+    }
+
+    fn take_crate(&self, stacks: &mut [VecDeque<char>]) -> anyhow::Result<char> {
+        stacks[self.from - 1]
+            .pop_back()
+            .ok_or_else(|| anyhow::anyhow!("no crate to move from stack {}", self.from))
+    }
+}
+
+/// Parses moves validating that they refer to one of `stacks_count` stacks.
+pub fn parse_moves(s: &str, stacks_count: usize) -> Result<Vec<Move>, ParseError> {
+    let p = Parser::new(DAY, s);
+    let parse_stack = |text| {
+        let stack = p.parse(text)?;
+        match (1..=stacks_count).contains(&stack) {
+            true => Ok(stack),
+            false => Err(p.error(text, format!("expected stack 1-{}", stacks_count))),
+        }
+    };
+
+    s.split("\n")
+        .map(|line| {
+            let (count, rem) = p.split_once(p.strip_prefix(line, "move ")?, " from ")?;
+            let (from, to) = p.split_once(rem, " to ")?;
+            Ok(Move {
+                count: p.parse(count)?,
+                from: parse_stack(from)?,
+                to: parse_stack(to)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<(Vec<VecDeque<char>>, Vec<Move>), ParseError> {
+    let p = Parser::new(DAY, input);
+
+    let blocks: Vec<Block> = read::str_blocks(DAY, input).collect();
+    let [drawing, moves] = blocks.as_slice() else {
+        return Err(p.error(
+            input,
+            "expected drawing and moves separated by a blank line",
+        ));
+    };
+    let stacks = drawing.within(parse_stacks(&drawing.text()))?;
+    let moves = moves.within(parse_moves(&moves.text(), stacks.len()))?;
+    Ok((stacks, moves))
+}
+
+fn top_crates(stacks: &[VecDeque<char>]) -> anyhow::Result<String> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            s.back()
+                .copied()
+                .ok_or_else(|| anyhow::anyhow!("stack {} is empty", i + 1))
+        })
+        .collect()
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<String> {
+    let (mut stacks, moves) = parse_input(input)?;
+
+    for m in moves {
+        m.apply_9000(&mut stacks)?;
+    }
+
+    top_crates(&stacks)
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<String> {
+    let (mut stacks, moves) = parse_input(input)?;
+
+    for m in moves {
+        m.apply_9001(&mut stacks)?;
+    }
+
+    top_crates(&stacks)
+}
+
+const INPUT: &str = r"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("CMZ"), Some("MCD")],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), String::from("CMZ"));
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), "MCD");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("move 2 from 2 to 1", "move 2 from 4 to 1");
+        let err = task_1(&input)
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (5, 8, 13));
+        assert_eq!(err.text, "4");
+
+        let input = INPUT.replace("move 1 from 1 to 2", "move 1 from 1 2");
+        let err = task_2(&input)
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (9, 13));
+
+        let input = INPUT.replace("move 3 from 1", "move 4 from 1");
+        assert!(task_1(&input).is_err());
+
+        let err = task_1(&INPUT.replace("\n\n", "\n"))
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (1, 1));
+        assert!(task_1(&format!("{}\n\nmove 1 from 1 to 2", INPUT)).is_err());
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), "CMZ");
+        assert_eq!(task_2(&input).expect("failed to run 2"), "MCD");
+    }
+
+    /// Drawing of 2 to 9 stacks followed by moves that never take more crates
+    /// than a stack has and leave every stack with a crate on top.
+    fn generate(rng: &mut Rng) -> String {
+        let count = rng.range(2, 9) as usize;
+        let mut stacks: Vec<Vec<char>> = (0..count)
+            .map(|_| {
+                (0..rng.range(0, 6))
+                    .map(|_| (b'A' + rng.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        while stacks.iter().map(Vec::len).sum::<usize>() < count {
+            let i = rng.below(count);
+            stacks[i].push('X');
+        }
+
+        let height = stacks.iter().map(Vec::len).max().unwrap();
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                let row: Vec<String> = stacks
+                    .iter()
+                    .map(|s| match s.get(level) {
+                        Some(c) => format!("[{}]", c),
+                        None => "   ".to_string(),
+                    })
+                    .collect();
+                row.join(" ")
+            })
+            .collect();
+        let numbers: Vec<String> = (1..=count).map(|i| format!(" {} ", i)).collect();
+        lines.push(numbers.join(" "));
+        lines.push(String::new());
+
+        let mut sizes: Vec<usize> = stacks.iter().map(Vec::len).collect();
+        let mut push_move = |sizes: &mut Vec<usize>, n: usize, from: usize, to: usize| {
+            sizes[from] -= n;
+            sizes[to] += n;
+            lines.push(format!("move {} from {} to {}", n, from + 1, to + 1));
+        };
+        for _ in 0..rng.range(1, 15) {
+            let filled: Vec<usize> = (0..count).filter(|&i| sizes[i] > 0).collect();
+            let from = *rng.choose(&filled);
+            let to = (from + rng.range(1, count as i64 - 1) as usize) % count;
+            let n = rng.range(1, sizes[from] as i64) as usize;
+            push_move(&mut sizes, n, from, to);
+        }
+        while let Some(empty) = sizes.iter().position(|s| *s == 0) {
+            let from = sizes.iter().position(|s| *s > 1).unwrap();
+            push_move(&mut sizes, 1, from, empty);
+        }
+        lines.join("\n")
+    }
+
+    /// Moves crates one by one, or whole groups when `keep_order` is set.
+    fn reference(input: &str, keep_order: bool) -> String {
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+        let rows: Vec<&[u8]> = drawing.lines().map(str::as_bytes).collect();
+        let (numbers, rows) = rows.split_last().unwrap();
+        let mut stacks: Vec<Vec<u8>> = (1..numbers.len())
+            .step_by(4)
+            .map(|col| {
+                let crates = rows.iter().rev().filter_map(|r| r.get(col));
+                crates.copied().filter(|c| *c != b' ').collect()
+            })
+            .collect();
+
+        for line in moves.lines() {
+            let n: Vec<usize> = line.split(' ').filter_map(|w| w.parse().ok()).collect();
+            let from = &mut stacks[n[1] - 1];
+            let mut moved = from.split_off(from.len() - n[0]);
+            if !keep_order {
+                moved.reverse();
+            }
+            stacks[n[2] - 1].extend(moved);
+        }
+        stacks.iter().map(|s| *s.last().unwrap() as char).collect()
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference(input, false));
+            assert_eq!(task_2(input).unwrap(), reference(input, true));
+        });
+    }
+}
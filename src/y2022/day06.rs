@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use crate::solutions::Example;
+
+pub fn task_1(input: &str) -> anyhow::Result<usize> {
+    let mut used = HashMap::new();
+
+    let chars: Vec<char> = input.chars().collect();
+    for (i, c) in chars.iter().enumerate() {
+        *used.entry(c).or_insert(0) += 1;
+
+        if used.len() == 4 {
+            return Ok(i + 1);
+        }
+        if i >= 3 {
+            let e = used.get_mut(&chars[i - 3]).unwrap();
+            *e -= 1;
+            if *e == 0 {
+                used.remove(&chars[i - 3]);
+            }
+        }
+    }
+
+    anyhow::bail!("expected to find begining")
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<usize> {
+    let mut used = HashMap::new();
+
+    let chars: Vec<char> = input.chars().collect();
+    for (i, c) in chars.iter().enumerate() {
+        *used.entry(c).or_insert(0) += 1;
+        if used.len() == 14 {
+            return Ok(i + 1);
+        }
+        if i >= 13 {
+            let e = used.get_mut(&chars[i - 13]).unwrap();
+            *e -= 1;
+            if *e == 0 {
+                used.remove(&chars[i - 13]);
+            }
+        }
+    }
+
+    anyhow::bail!("expected to find begining")
+}
+
+const INPUT: &str = r"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+const INPUT_2: &str = r"bvwbjplbgvbhsrlpgdmjqwftvncz";
+
+const INPUT_3: &str = r"nppdvjthqldpwncqszvftbrmjlhg";
+
+const INPUT_4: &str = r"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+
+const INPUT_5: &str = r"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "1",
+        input: INPUT,
+        expected: [Some("7"), Some("19")],
+    },
+    Example {
+        name: "2",
+        input: INPUT_2,
+        expected: [Some("5"), Some("23")],
+    },
+    Example {
+        name: "3",
+        input: INPUT_3,
+        expected: [Some("6"), Some("23")],
+    },
+    Example {
+        name: "4",
+        input: INPUT_4,
+        expected: [Some("10"), Some("29")],
+    },
+    Example {
+        name: "5",
+        input: INPUT_5,
+        expected: [Some("11"), Some("26")],
+    },
+];
+
+#[cfg(test)]
+mod test {
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use super::INPUT_2;
+    use super::INPUT_3;
+    use super::INPUT_4;
+    use super::INPUT_5;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 7);
+        assert_eq!(task_1(INPUT_2).expect("failed to run 1"), 5);
+        assert_eq!(task_1(INPUT_3).expect("failed to run 1"), 6);
+        assert_eq!(task_1(INPUT_4).expect("failed to run 1"), 10);
+        assert_eq!(task_1(INPUT_5).expect("failed to run 1"), 11);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 19);
+        assert_eq!(task_2(INPUT_2).expect("failed to run 2"), 23);
+        assert_eq!(task_2(INPUT_3).expect("failed to run 2"), 23);
+        assert_eq!(task_2(INPUT_4).expect("failed to run 2"), 29);
+        assert_eq!(task_2(INPUT_5).expect("failed to run 2"), 26);
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 7);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 19);
+    }
+
+    /// Random signal over a small alphabet with 14 different characters
+    /// somewhere in it, so both markers exist.
+    fn generate(rng: &mut Rng) -> String {
+        let alphabet: Vec<char> = ('a'..='z').collect();
+        let size = rng.range(2, 26) as usize;
+        let mut signal: Vec<char> = (0..rng.range(0, 60))
+            .map(|_| alphabet[rng.below(size)])
+            .collect();
+        let mut marker = alphabet.clone();
+        rng.shuffle(&mut marker);
+        let at = rng.below(signal.len() + 1);
+        signal.splice(at..at, marker.into_iter().take(14));
+        signal.extend((0..rng.range(0, 20)).map(|_| alphabet[rng.below(size)]));
+        signal.into_iter().collect()
+    }
+
+    /// Position after the first window of `len` pairwise different characters.
+    fn reference(input: &str, len: usize) -> usize {
+        let chars: Vec<char> = input.chars().collect();
+        (len..=chars.len())
+            .find(|&end| {
+                let window = &chars[end - len..end];
+                (0..len).all(|i| (i + 1..len).all(|j| window[i] != window[j]))
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference(input, 4));
+            assert_eq!(task_2(input).unwrap(), reference(input, 14));
+        });
+    }
+}
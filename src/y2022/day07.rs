@@ -0,0 +1,380 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+
+const DAY: u32 = 7;
+
+pub struct FsEntry {
+    pub parent: Option<Rc<RefCell<FsEntry>>>,
+    pub path: String,
+    pub content: FsContent,
+}
+
+pub enum FsContent {
+    File(usize),
+    Dir(Vec<Rc<RefCell<FsEntry>>>),
+}
+
+pub fn parse_fs_tree(input: &str) -> Result<Rc<RefCell<FsEntry>>, ParseError> {
+    let p = Parser::new(DAY, input);
+    let mut path: Vec<String> = vec!["/".to_string()];
+
+    let root_dir = Rc::new(RefCell::new(FsEntry {
+        parent: None,
+        path: "/".to_string(),
+        content: FsContent::Dir(vec![]),
+    }));
+
+    let mut curr_dir = root_dir.clone();
+
+    // TODO: refactor this...
+    for line in input.split("\n") {
+        let parts: Vec<&str> = line.split(" ").collect();
+        let part = |i: usize, expected: &str| {
+            parts
+                .get(i)
+                .copied()
+                .ok_or_else(|| p.error(line, format!("expected {}", expected)))
+        };
+        match parts[0] {
+            "$" => match part(1, "command")? {
+                "ls" => {}
+                "cd" => match part(2, "directory")? {
+                    dir @ ".." => {
+                        path.pop();
+                        let parent = { curr_dir.borrow().parent.clone() };
+                        curr_dir =
+                            parent.ok_or_else(|| p.error(dir, "cannot leave root directory"))?;
+                    }
+                    "/" => {
+                        path.truncate(1);
+                        curr_dir = root_dir.clone();
+                    }
+                    dir => {
+                        path.push(dir.to_string());
+                        let new_entry = Rc::new(RefCell::new(FsEntry {
+                            parent: Some(curr_dir.clone()),
+                            path: path.join("/"),
+                            content: FsContent::Dir(vec![]),
+                        }));
+                        {
+                            let mut cur_dir_mut = curr_dir.borrow_mut();
+                            if let FsContent::Dir(children) = &mut cur_dir_mut.content {
+                                children.push(new_entry.clone());
+                            } else {
+                                panic!("unexpected thingy");
+                            }
+                        }
+                        curr_dir = new_entry;
+                    }
+                },
+                cmd => return Err(p.error(cmd, "unexpected command")),
+            },
+            "dir" => {}
+            num => {
+                let size: usize = p.parse(num)?;
+                let new_entry = Rc::new(RefCell::new(FsEntry {
+                    parent: Some(curr_dir.clone()),
+                    path: path.join("/"),
+                    content: FsContent::File(size),
+                }));
+                let mut cur_dir_mut = curr_dir.borrow_mut();
+                if let FsContent::Dir(children) = &mut cur_dir_mut.content {
+                    children.push(new_entry);
+                } else {
+                    panic!("unexpected thingy");
+                }
+            }
+        }
+    }
+    Ok(root_dir)
+}
+
+pub fn entry_size(fs_entry: Rc<RefCell<FsEntry>>, sizes: &mut HashMap<String, usize>) -> usize {
+    let entry = fs_entry.borrow();
+
+    match &entry.content {
+        FsContent::File(size) => *size,
+        FsContent::Dir(ch) => {
+            let sum = ch
+                .iter()
+                .map(|e| entry_size(e.clone(), sizes))
+                .sum::<usize>();
+            *sizes.entry(entry.path.clone()).or_insert(0) = sum;
+            sum
+        }
+    }
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<usize> {
+    solve_1(parse_fs_tree(input)?)
+}
+
+pub fn solve_1(root_dir: Rc<RefCell<FsEntry>>) -> anyhow::Result<usize> {
+    let mut sizes: HashMap<String, usize> = HashMap::new();
+    entry_size(root_dir, &mut sizes);
+
+    let mut sum = 0;
+    for (_k, v) in sizes {
+        if v <= 100000 {
+            sum += v;
+        }
+    }
+
+    Ok(sum)
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<usize> {
+    solve_2(parse_fs_tree(input)?)
+}
+
+pub fn solve_2(root_dir: Rc<RefCell<FsEntry>>) -> anyhow::Result<usize> {
+    let mut sizes: HashMap<String, usize> = HashMap::new();
+    let root_size = entry_size(root_dir, &mut sizes);
+
+    let disk_space: usize = 70000000;
+    let update_space: usize = 30000000;
+    let Some(av_space) = disk_space.checked_sub(root_size) else {
+        anyhow::bail!("used space {} does not fit on the disk", root_size)
+    };
+    let need_space = match update_space.checked_sub(av_space) {
+        Some(need_space) if need_space > 0 => need_space,
+        _ => anyhow::bail!("free space {} is enough for the update", av_space),
+    };
+
+    let mut min_ok_size = root_size;
+    let mut min_diff = root_size - need_space;
+
+    for (_k, v) in sizes {
+        let diff = v as i64 - need_space as i64;
+        if diff >= 0 && diff < min_diff as i64 {
+            min_diff = diff as usize;
+            min_ok_size = v;
+        }
+    }
+
+    Ok(min_ok_size)
+}
+
+const INPUT: &str = r"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("95437"), Some("24933642")],
+}];
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 95437);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 24933642);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("29116 f", "29l16 f");
+        let err = task_1(&input)
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (7, 10, 1));
+        assert_eq!(err.text, "29l16");
+
+        let err = task_1("$ cd /\n$ cd ..")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (2, 6));
+
+        assert!(task_2("$ cd /\n$ rm x").is_err());
+        assert!(task_2("$ cd /\n$").is_err());
+    }
+
+    #[test]
+    fn test_cd_root() {
+        // `cd /` returns to the root instead of creating a directory in it.
+        assert_eq!(task_1("$ cd /\n$ ls\n100 a").unwrap(), 100);
+        assert_eq!(
+            task_1("$ cd /\n$ cd x\n$ ls\n100 a\n$ cd /\n$ ls\n5 b").unwrap(),
+            205
+        );
+    }
+
+    #[test]
+    fn test_no_cleanup_needed() {
+        let err = task_2("$ cd /\n$ ls\n100 a").expect_err("expected error");
+        assert!(err.to_string().contains("enough for the update"), "{}", err);
+        let err = task_2("$ cd /\n$ ls\n80000000 a").expect_err("expected error");
+        assert!(err.to_string().contains("does not fit"), "{}", err);
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 95437);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 24933642);
+    }
+
+    #[derive(Default)]
+    struct Dir {
+        files: Vec<(String, usize)>,
+        dirs: Vec<(String, Dir)>,
+    }
+
+    impl Dir {
+        fn random(rng: &mut Rng, depth: usize) -> Dir {
+            let mut dir = Dir::default();
+            for i in 0..rng.range(0, 4) {
+                let name = format!("{}.txt", (b'a' + i as u8) as char);
+                dir.files.push((name, rng.range(1, 60000) as usize));
+            }
+            if depth < 4 {
+                for i in 0..rng.range(0, 3) {
+                    let name = ((b'p' + i as u8) as char).to_string();
+                    dir.dirs.push((name, Dir::random(rng, depth + 1)));
+                }
+            }
+            dir
+        }
+
+        fn size(&self) -> usize {
+            let files: usize = self.files.iter().map(|(_, s)| s).sum();
+            files + self.dirs.iter().map(|(_, d)| d.size()).sum::<usize>()
+        }
+
+        /// Adds a file to a random directory of the tree.
+        fn add_file(&mut self, rng: &mut Rng, name: String, size: usize) {
+            if self.dirs.is_empty() || rng.chance(30) {
+                self.files.push((name, size));
+            } else {
+                let i = rng.below(self.dirs.len());
+                self.dirs[i].1.add_file(rng, name, size);
+            }
+        }
+
+        fn transcript(&self, rng: &mut Rng, lines: &mut Vec<String>) {
+            lines.push("$ ls".to_string());
+            let mut listing: Vec<String> = self
+                .files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name))
+                .chain(self.dirs.iter().map(|(name, _)| format!("dir {}", name)))
+                .collect();
+            rng.shuffle(&mut listing);
+            lines.extend(listing);
+            for (name, dir) in &self.dirs {
+                lines.push(format!("$ cd {}", name));
+                dir.transcript(rng, lines);
+                lines.push("$ cd ..".to_string());
+            }
+        }
+    }
+
+    /// Shell session listing every directory once. Most have enough data on
+    /// the disk for the update to need a directory removed, the rest need no
+    /// cleanup.
+    fn generate(rng: &mut Rng) -> String {
+        let mut root = Dir::random(rng, 0);
+        let fill = rng.chance(80);
+        while fill && root.size() <= 40_000_000 {
+            let size = rng.range(5_000_000, 15_000_000) as usize;
+            root.add_file(rng, format!("big{}", size), size);
+        }
+
+        let mut lines = vec!["$ cd /".to_string()];
+        root.transcript(rng, &mut lines);
+        // The session does not have to go back to the root.
+        while lines.last().unwrap() == "$ cd .." && rng.chance(50) {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+
+    /// Sizes of every visited directory, files are added to all directories
+    /// on their path.
+    fn reference_sizes(input: &str) -> HashMap<String, usize> {
+        let mut sizes = HashMap::new();
+        let mut path: Vec<&str> = Vec::new();
+        for line in input.lines() {
+            match line.split(' ').collect::<Vec<_>>()[..] {
+                ["$", "cd", ".."] => {
+                    path.pop();
+                }
+                ["$", "cd", dir] => {
+                    path.push(dir);
+                    sizes.insert(path.join("/"), 0);
+                }
+                ["$", "ls"] | ["dir", _] => {}
+                [size, _] => {
+                    for i in 1..=path.len() {
+                        *sizes.get_mut(&path[..i].join("/")).unwrap() +=
+                            size.parse::<usize>().unwrap();
+                    }
+                }
+                _ => panic!("unexpected line {}", line),
+            }
+        }
+        sizes
+    }
+
+    fn reference_1(input: &str) -> usize {
+        let sizes = reference_sizes(input);
+        sizes.values().filter(|s| **s <= 100000).sum()
+    }
+
+    /// `None` when there is already enough free space for the update.
+    fn reference_2(input: &str) -> Option<usize> {
+        let sizes = reference_sizes(input);
+        let need = sizes["/"].checked_sub(40_000_000).filter(|n| *n > 0)?;
+        sizes.values().filter(|s| **s >= need).min().copied()
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference_1(input));
+            match reference_2(input) {
+                Some(expected) => assert_eq!(task_2(input).unwrap(), expected),
+                None => assert!(task_2(input).is_err()),
+            }
+        });
+    }
+}
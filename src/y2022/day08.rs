@@ -0,0 +1,218 @@
+use crate::solutions::Example;
+use crate::util::geom::Direction;
+use crate::util::{parse_to_digit_grid, Grid};
+
+const DAY: u32 = 8;
+
+fn parse_trees(input: &str) -> anyhow::Result<Grid<u32>> {
+    let trees_grid = parse_to_digit_grid(input).map_err(|e| e.with_day(DAY))?;
+    anyhow::ensure!(
+        trees_grid.width() >= 2 && trees_grid.height() >= 2,
+        "expected at least 2x2 grid of trees"
+    );
+    Ok(trees_grid)
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<usize> {
+    let trees_grid = parse_trees(input)?;
+
+    let rows_count = trees_grid.height();
+    let cols_count = trees_grid.width();
+
+    // Get all outer trees
+    let mut visable_trees = (2 * rows_count) + (2 * (cols_count - 2));
+
+    let mut max_top = trees_grid.row(0).to_vec();
+    let mut max_left: Vec<u32> = trees_grid.column(0).copied().collect();
+    let mut visable = Grid::new(cols_count, rows_count, false);
+
+    for row in 1..rows_count - 1 {
+        for col in 1..cols_count - 1 {
+            let tree = trees_grid[(col, row)];
+            visable[(col, row)] = tree > max_top[col] || tree > max_left[row];
+            max_top[col] = max_top[col].max(tree);
+            max_left[row] = max_left[row].max(tree);
+        }
+    }
+
+    let mut max_bottom = trees_grid.row(rows_count - 1).to_vec();
+    let mut max_right: Vec<u32> = trees_grid.column(cols_count - 1).copied().collect();
+
+    for row in (1..rows_count - 1).rev() {
+        for col in (1..cols_count - 1).rev() {
+            let tree = trees_grid[(col, row)];
+            visable[(col, row)] =
+                visable[(col, row)] || tree > max_bottom[col] || tree > max_right[row];
+            max_bottom[col] = max_bottom[col].max(tree);
+            max_right[row] = max_right[row].max(tree);
+        }
+    }
+
+    visable_trees += visable
+        .iter()
+        .filter(|(_, is_visable)| **is_visable)
+        .count();
+
+    Ok(visable_trees)
+}
+
+/// Checks every tree against all trees towards each edge, used to cross-check
+/// the two pass scan of [`task_1`].
+pub fn naive_1(input: &str) -> anyhow::Result<usize> {
+    let trees_grid = parse_trees(input)?;
+
+    let visible_trees = trees_grid
+        .iter()
+        .filter(|(pos, height)| {
+            Direction::ALL
+                .into_iter()
+                .any(|d| trees_grid.ray(*pos, d).all(|(_, tree)| tree < height))
+        })
+        .count();
+
+    Ok(visible_trees)
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<usize> {
+    let trees_grid = parse_trees(input)?;
+
+    let max_score = trees_grid
+        .positions()
+        .map(|pos| {
+            Direction::ALL
+                .into_iter()
+                .map(|d| view_distance(&trees_grid, pos, d))
+                .product::<u32>()
+        })
+        .max()
+        .unwrap_or(0);
+
+    Ok(max_score as usize)
+}
+
+pub fn view_distance(trees: &Grid<u32>, pos: (usize, usize), direction: Direction) -> u32 {
+    let mut view_dist = 0;
+    for (_, tree) in trees.ray(pos, direction) {
+        view_dist += 1;
+        if *tree >= trees[pos] {
+            break;
+        }
+    }
+
+    view_dist
+}
+
+const INPUT: &str = r"30373
+25512
+65332
+33549
+35390";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("21"), Some("8")],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::naive_1;
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 21);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 8);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1("303\n2a5\n653")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (8, 2, 2));
+
+        assert!(task_2("303\n25\n653").is_err());
+        assert!(task_1("3").is_err());
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 21);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 8);
+    }
+
+    fn generate(rng: &mut Rng) -> String {
+        let (rows, cols) = (rng.range(2, 10), rng.range(2, 10));
+        let max_height = rng.range(1, 9);
+        let lines: Vec<String> = (0..rows)
+            .map(|_| {
+                (0..cols)
+                    .map(|_| rng.range(0, max_height).to_string())
+                    .collect()
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Trees seen from every tree in the four directions, up to and including
+    /// the first one that blocks the view, and whether the edge was reached.
+    fn views(input: &str) -> Vec<[(usize, bool); 4]> {
+        let grid: Vec<Vec<u8>> = input.lines().map(|l| l.bytes().collect()).collect();
+        let (rows, cols) = (grid.len() as i32, grid[0].len() as i32);
+        let mut views = Vec::new();
+        for r in 0..rows {
+            for c in 0..cols {
+                let height = grid[r as usize][c as usize];
+                views.push([(-1, 0), (1, 0), (0, -1), (0, 1)].map(|(dr, dc)| {
+                    let (mut rr, mut cc, mut seen) = (r + dr, c + dc, 0);
+                    while (0..rows).contains(&rr) && (0..cols).contains(&cc) {
+                        seen += 1;
+                        if grid[rr as usize][cc as usize] >= height {
+                            return (seen, false);
+                        }
+                        rr += dr;
+                        cc += dc;
+                    }
+                    (seen, true)
+                }));
+            }
+        }
+        views
+    }
+
+    fn reference_1(input: &str) -> usize {
+        views(input)
+            .iter()
+            .filter(|v| v.iter().any(|(_, edge)| *edge))
+            .count()
+    }
+
+    fn reference_2(input: &str) -> usize {
+        views(input)
+            .iter()
+            .map(|v| v.iter().map(|(seen, _)| seen).product())
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference_1(input));
+            assert_eq!(naive_1(input).unwrap(), reference_1(input));
+            assert_eq!(task_2(input).unwrap(), reference_2(input));
+        });
+    }
+}
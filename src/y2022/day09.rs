@@ -0,0 +1,231 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::util::geom::{Direction, Point, Vec2};
+
+const DAY: u32 = 9;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    pub direction: Direction,
+    pub distance: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let (direction, distance) = p.split_once(s, " ")?;
+        let direction = match direction {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(p.error(direction, "invalid direction")),
+        };
+        let distance = p.parse::<usize>(distance)?;
+        Ok(Move {
+            direction,
+            distance,
+        })
+    }
+}
+
+pub fn parse_moves(input: &str) -> Result<Vec<Move>, ParseError> {
+    let p = Parser::new(DAY, input);
+
+    input
+        .lines()
+        .map(|line| p.within(line, Move::from_str(line)))
+        .collect()
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<usize> {
+    let moves = parse_moves(input)?;
+
+    let mut visited = HashSet::new();
+
+    let mut head_pos = Point::ORIGIN;
+    let mut tail_pos = Point::ORIGIN;
+
+    visited.insert(tail_pos);
+
+    for m in moves {
+        // TODO: this is done really naively, we could do it better if the
+        // number of moves is large.
+        for _i in 0..m.distance {
+            let new_head_pos = head_pos + m.direction.vec();
+
+            if let Some(move_vector) = need_to_move(new_head_pos, tail_pos) {
+                tail_pos += move_vector;
+                visited.insert(tail_pos);
+            }
+
+            head_pos = new_head_pos;
+        }
+    }
+
+    Ok(visited.len())
+}
+
+/// Step of the tail towards the head if they are no longer touching.
+fn need_to_move(head: Point, tail: Point) -> Option<Vec2> {
+    let m = head - tail;
+    if m.chebyshev_len() <= 1 {
+        return None;
+    }
+    Some(m.signum())
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<usize> {
+    let moves = parse_moves(input)?;
+
+    let mut visited = HashSet::new();
+
+    let mut head_pos = Point::ORIGIN;
+    let mut tail_pos = [Point::ORIGIN; 9];
+
+    for m in moves {
+        // TODO: this is done really naively, we could do it better if the
+        // number of moves is large.
+        for _i in 0..m.distance {
+            let new_head_pos = head_pos + m.direction.vec();
+            let mut check_pos = new_head_pos;
+
+            for tail in tail_pos.iter_mut() {
+                if let Some(move_vector) = need_to_move(check_pos, *tail) {
+                    *tail += move_vector;
+                }
+                check_pos = *tail;
+            }
+
+            visited.insert(tail_pos[8]);
+
+            head_pos = new_head_pos;
+        }
+    }
+
+    Ok(visited.len())
+}
+
+const INPUT: &str = r"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+const INPUT2: &str = r"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "1",
+        input: INPUT,
+        expected: [Some("13"), Some("1")],
+    },
+    Example {
+        name: "2",
+        input: INPUT2,
+        expected: [None, Some("36")],
+    },
+];
+
+#[cfg(test)]
+mod test {
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use super::INPUT2;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 13);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1("R 4\nU 4\nX 3")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (9, 3, 1));
+
+        let err = task_2("R 4\nU -4")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "-4");
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 1);
+        assert_eq!(task_2(INPUT2).expect("failed to run 2"), 36);
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 13);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 1);
+    }
+
+    fn generate(rng: &mut Rng) -> String {
+        let moves: Vec<String> = (0..rng.range(1, 40))
+            .map(|_| format!("{} {}", rng.choose(&["U", "D", "L", "R"]), rng.range(1, 12)))
+            .collect();
+        moves.join("\n")
+    }
+
+    /// Positions visited by the last of `knots`, each knot steps towards the
+    /// previous one whenever they stop touching.
+    fn reference(input: &str, knots: usize) -> usize {
+        let mut rope = vec![(0i64, 0i64); knots];
+        let mut visited = vec![(0, 0)];
+        for line in input.lines() {
+            let (dir, n) = line.split_once(' ').unwrap();
+            let (dx, dy) = match dir {
+                "U" => (0, 1),
+                "D" => (0, -1),
+                "L" => (-1, 0),
+                _ => (1, 0),
+            };
+            for _ in 0..n.parse::<usize>().unwrap() {
+                rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+                for i in 1..knots {
+                    let (x, y) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                    if x.abs() > 1 || y.abs() > 1 {
+                        rope[i] = (rope[i].0 + x.signum(), rope[i].1 + y.signum());
+                    }
+                }
+                if !visited.contains(&rope[knots - 1]) {
+                    visited.push(rope[knots - 1]);
+                }
+            }
+        }
+        visited.len()
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference(input, 2));
+            assert_eq!(task_2(input).unwrap(), reference(input, 10));
+        });
+    }
+}
@@ -0,0 +1,384 @@
+use std::str::FromStr;
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::{debug, trace};
+
+const DAY: u32 = 10;
+
+pub enum Instruction {
+    Addx(i64),
+    Noop,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let parts = s.split(" ").collect::<Vec<_>>();
+        match parts[0] {
+            "addx" => match parts.get(1) {
+                Some(n) => Ok(Instruction::Addx(p.parse::<i64>(n)?)),
+                None => Err(p.error(s, "expected addx argument")),
+            },
+            "noop" => Ok(Instruction::Noop),
+            ins => Err(p.error(ins, "unexpected instruction")),
+        }
+    }
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let p = Parser::new(DAY, input);
+
+    input
+        .lines()
+        .map(|line| p.within(line, Instruction::from_str(line)))
+        .collect()
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<i64> {
+    let instructions = parse_instructions(input)?;
+
+    let mut x = 1;
+    let mut cycles = 0;
+    let mut signals_sum = 0;
+
+    for ins in instructions {
+        match ins {
+            Instruction::Addx(n) => {
+                cycles += 2;
+
+                // Addx instruction takes two cycles to execute so we can go
+                // from 59->61, therefore we subtract reminder of division by 2
+                // to make sure we are always check even cycle.
+                let cyc_check = cycles - cycles % 2;
+                if (cyc_check - 20) % 40 == 0 {
+                    debug!("counting on cycle: {}, x: {}", cyc_check, x);
+                    signals_sum += cyc_check * x;
+                }
+                x += n;
+            }
+            Instruction::Noop => {
+                cycles += 1;
+                if (cycles - 20) % 40 == 0 {
+                    debug!("counting on cycle (noop): {}, x: {}", cycles, x);
+                    signals_sum += cycles * x;
+                }
+            }
+        }
+    }
+
+    Ok(signals_sum)
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<String> {
+    let instructions = parse_instructions(input)?;
+
+    let mut screen = vec!['.'; 240];
+
+    let mut x: i64 = 1;
+    let mut cycles: usize = 0;
+
+    for ins in instructions {
+        match ins {
+            Instruction::Addx(n) => {
+                for _i in 0..2 {
+                    cycles += 1;
+                    draw_pixels(&mut screen, x, cycles);
+                }
+                x += n;
+            }
+            Instruction::Noop => {
+                cycles += 1;
+                draw_pixels(&mut screen, x, cycles);
+            }
+        }
+    }
+
+    let mut out = String::new();
+    for i in 0..(240 / 40) {
+        out.push_str(&screen[i * 40..(i + 1) * 40].iter().collect::<String>());
+        out.push('\n');
+    }
+    debug!("screen:\n{}", out);
+
+    Ok(out)
+}
+
+fn draw_pixels(screen: &mut [char], x: i64, cycles: usize) {
+    let c = (cycles as i64 - 1) % 40;
+    trace!("cycle: {}, sprite at: {}, drawing column: {}", cycles, x, c);
+
+    for xp in x - 1..x + 2 {
+        if xp == c {
+            screen[cycles - 1] = '#';
+            break;
+        }
+    }
+}
+
+const INPUT: &str = r"addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+const P2_OUT: &str = r"##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("13140"), Some(P2_OUT)],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use super::P2_OUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 13140);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1("noop\naddx 1O")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (10, 2, 6));
+
+        let err = task_2("noop\nmul 3")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "mul");
+
+        assert!(task_1("addx").is_err());
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), P2_OUT.to_string());
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 13140);
+        assert_eq!(task_2(&input).expect("failed to run 2"), P2_OUT.to_string());
+    }
+
+    /// Program running for exactly 240 cycles, like the puzzle inputs.
+    fn generate(rng: &mut Rng) -> String {
+        let mut lines = Vec::new();
+        let mut cycles = 0;
+        while cycles < 240 {
+            if cycles < 239 && rng.chance(70) {
+                lines.push(format!("addx {}", rng.range(-12, 12)));
+                cycles += 2;
+            } else {
+                lines.push("noop".to_string());
+                cycles += 1;
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Value of X during each cycle.
+    fn register(input: &str) -> Vec<i64> {
+        let mut x = 1;
+        let mut during = Vec::new();
+        for line in input.lines() {
+            during.push(x);
+            if let Some(n) = line.strip_prefix("addx ") {
+                during.push(x);
+                x += n.parse::<i64>().unwrap();
+            }
+        }
+        during
+    }
+
+    fn reference_1(input: &str) -> i64 {
+        let during = register(input);
+        [20, 60, 100, 140, 180, 220]
+            .iter()
+            .map(|&cycle| cycle * during[cycle as usize - 1])
+            .sum()
+    }
+
+    fn reference_2(input: &str) -> String {
+        let mut screen = String::new();
+        for (i, x) in register(input).into_iter().enumerate() {
+            let column = (i % 40) as i64;
+            screen.push(if (x - column).abs() <= 1 { '#' } else { '.' });
+            if column == 39 {
+                screen.push('\n');
+            }
+        }
+        screen
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference_1(input));
+            assert_eq!(task_2(input).unwrap(), reference_2(input));
+        });
+    }
+}
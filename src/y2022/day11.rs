@@ -0,0 +1,457 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use crate::cancel;
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::util::read::{self, Block};
+
+const DAY: u32 = 11;
+
+pub struct Calculation {
+    pub op1: Operand,
+    pub op2: Operand,
+    pub operation: Operation,
+}
+
+impl Calculation {
+    pub fn calculate(&self, old: i64) -> i64 {
+        let op1 = match self.op1 {
+            Operand::Old => old,
+            Operand::Value(v) => v,
+        };
+        let op2 = match self.op2 {
+            Operand::Old => old,
+            Operand::Value(v) => v,
+        };
+        match self.operation {
+            Operation::Multiply => op1 * op2,
+            Operation::Add => op1 + op2,
+        }
+    }
+}
+
+impl FromStr for Calculation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let parts = s.split(" ").collect::<Vec<_>>();
+        if parts.len() != 3 {
+            return Err(p.error(s, "expected operation with two operands"));
+        }
+        let operand = |s| match s {
+            "old" => Ok(Operand::Old),
+            s => p.parse::<i64>(s).map(Operand::Value),
+        };
+        let op1 = operand(parts[0])?;
+        let op2 = operand(parts[2])?;
+        let operation = match parts[1] {
+            "*" => Operation::Multiply,
+            "+" => Operation::Add,
+            s => return Err(p.error(s, "unexpected operation")),
+        };
+        Ok(Calculation {
+            op1,
+            op2,
+            operation,
+        })
+    }
+}
+
+pub enum Operand {
+    Old,
+    Value(i64),
+}
+
+pub enum Operation {
+    Multiply,
+    Add,
+}
+
+pub struct MonkeyLogic {
+    pub calculation: Calculation,
+    pub test_div: i64,
+    pub true_dest: usize,
+    pub false_dest: usize,
+}
+
+pub struct MonkeySimulation {
+    pub monkeys: Vec<MonkeyLogic>,
+    pub item_queues: Vec<VecDeque<i64>>,
+
+    pub inspect_count: Vec<usize>,
+    pub div_factor: i64,
+}
+
+impl MonkeySimulation {
+    pub fn run_simulation(&mut self, rounds: usize, worry_div: Option<i64>) -> anyhow::Result<()> {
+        for _ in 0..rounds {
+            cancel::check()?;
+            for (i, monkey) in self.monkeys.iter().enumerate() {
+                while let Some(item) = self.item_queues[i].pop_front() {
+                    self.inspect_count[i] += 1;
+                    let mut new_worry = monkey.calculation.calculate(item);
+                    if let Some(worry_div) = worry_div {
+                        new_worry /= worry_div;
+                    } else {
+                        new_worry %= self.div_factor;
+                    }
+
+                    if new_worry % monkey.test_div == 0 {
+                        self.item_queues[monkey.true_dest].push_back(new_worry);
+                    } else {
+                        self.item_queues[monkey.false_dest].push_back(new_worry);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the block describing monkey `index` out of `count` monkeys.
+fn parse_monkey(
+    s: &str,
+    index: usize,
+    count: usize,
+) -> Result<(VecDeque<i64>, MonkeyLogic), ParseError> {
+    let p = Parser::new(DAY, s);
+    let parse_dest = |text| {
+        let dest = p.parse::<usize>(text)?;
+        if dest >= count {
+            return Err(p.error(text, "no such monkey"));
+        }
+        // The monkey would keep catching its own items forever.
+        if dest == index {
+            return Err(p.error(text, "monkey cannot throw to itself"));
+        }
+        Ok(dest)
+    };
+
+    let lines = s.lines().collect::<Vec<_>>();
+    if lines.len() != 6 {
+        return Err(p.error(s, "expected 6 lines describing a monkey"));
+    }
+    let items = p.strip_prefix(lines[1], "  Starting items: ")?;
+    let items = match items {
+        "" => VecDeque::new(),
+        items => items
+            .split(", ")
+            .map(|s| p.parse::<i64>(s))
+            .collect::<Result<VecDeque<_>, _>>()?,
+    };
+    let operation = p.strip_prefix(lines[2], "  Operation: new = ")?;
+    let calculation = p.within(operation, Calculation::from_str(operation))?;
+    let test = p.strip_prefix(lines[3], "  Test: divisible by ")?;
+    let test_div = p.parse::<i64>(test)?;
+    if test_div <= 0 {
+        return Err(p.error(test, "expected positive divisor"));
+    }
+    let true_dest = p.strip_prefix(lines[4], "    If true: throw to monkey ")?;
+    let false_dest = p.strip_prefix(lines[5], "    If false: throw to monkey ")?;
+    Ok((
+        items,
+        MonkeyLogic {
+            calculation,
+            test_div,
+            true_dest: parse_dest(true_dest)?,
+            false_dest: parse_dest(false_dest)?,
+        },
+    ))
+}
+
+pub fn parse_monkeys(input: &str) -> Result<MonkeySimulation, ParseError> {
+    let blocks: Vec<Block> = read::str_blocks(DAY, input).collect();
+    let count = blocks.len();
+    let data = blocks
+        .iter()
+        .enumerate()
+        .map(|(i, block)| block.within(parse_monkey(&block.text(), i, count)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut monkeys = Vec::new();
+    let mut item_queues = Vec::new();
+
+    for (items, logic) in data {
+        monkeys.push(logic);
+        item_queues.push(items);
+    }
+
+    let inspect_count = vec![0; monkeys.len()];
+    let div_factor = monkeys.iter().map(|m| m.test_div).product();
+
+    Ok(MonkeySimulation {
+        monkeys,
+        item_queues,
+        inspect_count,
+        div_factor,
+    })
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<usize> {
+    solve_1(parse_monkeys(input)?)
+}
+
+pub fn solve_1(mut simulation: MonkeySimulation) -> anyhow::Result<usize> {
+    simulation.run_simulation(20, Some(3))?;
+
+    Ok(monkey_business(&simulation.inspect_count))
+}
+
+/// Product of inspection counts of the two most active monkeys.
+pub fn monkey_business(inspect_count: &[usize]) -> usize {
+    let mut counts = inspect_count.to_vec();
+    counts.sort_unstable();
+
+    counts.iter().rev().take(2).product::<usize>()
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<usize> {
+    solve_2(parse_monkeys(input)?)
+}
+
+pub fn solve_2(mut simulation: MonkeySimulation) -> anyhow::Result<usize> {
+    simulation.run_simulation(10000, None)?;
+
+    Ok(monkey_business(&simulation.inspect_count))
+}
+
+/// Runs the second task keeping remainders of every item for each divisor
+/// separately, used to cross-check the reduction by `div_factor`.
+pub fn naive_2(input: &str) -> anyhow::Result<usize> {
+    let simulation = parse_monkeys(input)?;
+    let divisors: Vec<i64> = simulation.monkeys.iter().map(|m| m.test_div).collect();
+
+    let mut item_queues: Vec<VecDeque<Vec<i64>>> = simulation
+        .item_queues
+        .iter()
+        .map(|queue| {
+            queue
+                .iter()
+                .map(|item| divisors.iter().map(|d| item % d).collect())
+                .collect()
+        })
+        .collect();
+    let mut inspect_count = vec![0; divisors.len()];
+
+    for _ in 0..10000 {
+        cancel::check()?;
+        for (i, monkey) in simulation.monkeys.iter().enumerate() {
+            while let Some(mut remainders) = item_queues[i].pop_front() {
+                inspect_count[i] += 1;
+                for (r, d) in remainders.iter_mut().zip(&divisors) {
+                    *r = monkey.calculation.calculate(*r) % d;
+                }
+
+                if remainders[i] == 0 {
+                    item_queues[monkey.true_dest].push_back(remainders);
+                } else {
+                    item_queues[monkey.false_dest].push_back(remainders);
+                }
+            }
+        }
+    }
+
+    Ok(monkey_business(&inspect_count))
+}
+
+const INPUT: &str = r"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("10605"), Some("2713310158")],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use super::{monkey_business, naive_2};
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 10605);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 2713310158);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("old * 19", "old / 19");
+        let err = task_1(&input)
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (11, 3, 24));
+        assert_eq!(err.text, "/");
+
+        let input = INPUT.replace("throw to monkey 3", "throw to monkey 7");
+        let err = task_2(&input)
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (6, 31));
+
+        let input = INPUT.replacen("throw to monkey 2", "throw to monkey 0", 1);
+        let err = task_2(&input)
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (5, 30));
+        assert_eq!(err.message, "monkey cannot throw to itself");
+
+        assert!(task_1(&INPUT.replace("79, 98", "79,98")).is_err());
+        assert!(task_1(&INPUT.replace("divisible by 23", "divisible by 0")).is_err());
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 10605);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 2713310158);
+    }
+
+    const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+    fn random_monkeys(rng: &mut Rng) -> String {
+        let count = rng.range(2, 8) as usize;
+        let mut divisors = PRIMES.to_vec();
+        rng.shuffle(&mut divisors);
+
+        let monkeys: Vec<String> = (0..count)
+            .map(|i| {
+                let items: Vec<String> = (0..rng.range(0, 5))
+                    .map(|_| rng.range(1, 99).to_string())
+                    .collect();
+                let operation = match rng.below(4) {
+                    0 => "old * old".to_string(),
+                    1 => "old + old".to_string(),
+                    2 => format!("old * {}", rng.range(2, 19)),
+                    _ => format!("old + {}", rng.range(1, 8)),
+                };
+                let mut other = || (i + rng.range(1, count as i64 - 1) as usize) % count;
+                let (true_dest, false_dest) = (other(), other());
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                    i,
+                    items.join(", "),
+                    operation,
+                    divisors[i],
+                    true_dest,
+                    false_dest
+                )
+            })
+            .collect();
+        monkeys.join("\n\n")
+    }
+
+    /// Random monkeys whose worry levels stay in range during the first
+    /// part, which does not keep them small.
+    fn generate(rng: &mut Rng) -> String {
+        loop {
+            let input = random_monkeys(rng);
+            if reference_1(&input).is_some() {
+                return input;
+            }
+        }
+    }
+
+    struct Monkey {
+        items: Vec<u64>,
+        operation: Vec<String>,
+        divisor: u64,
+        dest: [usize; 2],
+    }
+
+    fn parse(input: &str) -> Vec<Monkey> {
+        input
+            .split("\n\n")
+            .map(|monkey| {
+                let last_word = |l: &str| l.rsplit(' ').next().unwrap().to_string();
+                let lines: Vec<&str> = monkey.lines().collect();
+                let items = lines[1].split(": ").nth(1).unwrap();
+                Monkey {
+                    items: items.split(", ").filter_map(|i| i.parse().ok()).collect(),
+                    operation: lines[2].split(' ').skip(6).map(String::from).collect(),
+                    divisor: last_word(lines[3]).parse().unwrap(),
+                    dest: [
+                        last_word(lines[4]).parse().unwrap(),
+                        last_word(lines[5]).parse().unwrap(),
+                    ],
+                }
+            })
+            .collect()
+    }
+
+    /// Applies the operation with `calc`, which gets the operator and value
+    /// of the right hand side, `None` for `old`.
+    fn apply<T>(operation: &[String], calc: impl Fn(&str, Option<u64>) -> T) -> T {
+        calc(&operation[0], operation[1].parse().ok())
+    }
+
+    /// Plain simulation, `None` if worry levels overflow.
+    fn reference_1(input: &str) -> Option<usize> {
+        let mut monkeys = parse(input);
+        let mut inspected = vec![0; monkeys.len()];
+        for _ in 0..20 {
+            for i in 0..monkeys.len() {
+                for item in std::mem::take(&mut monkeys[i].items) {
+                    inspected[i] += 1;
+                    let worry = apply(&monkeys[i].operation, |op, v| match op {
+                        "*" => item.checked_mul(v.unwrap_or(item)),
+                        _ => item.checked_add(v.unwrap_or(item)),
+                    })? / 3;
+                    if worry > i64::MAX as u64 / 2 {
+                        return None;
+                    }
+                    let dest = monkeys[i].dest[(worry % monkeys[i].divisor != 0) as usize];
+                    monkeys[dest].items.push(worry);
+                }
+            }
+        }
+        Some(monkey_business(&inspected))
+    }
+
+    #[test]
+    fn test_random() {
+        // The second part simulates 10000 rounds, so fewer cases are checked.
+        for_random_inputs(CASES / 10, generate, |input| {
+            assert_eq!(Some(task_1(input).unwrap()), reference_1(input));
+            assert_eq!(task_2(input).unwrap(), naive_2(input).unwrap());
+        });
+    }
+}
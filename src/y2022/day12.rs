@@ -0,0 +1,229 @@
+use crate::debug;
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::util::{parse_to_char_grid, search, Grid};
+
+const DAY: u32 = 12;
+
+pub fn square_height(c: char) -> i64 {
+    match c {
+        'a'..='z' => (c as i64) - ('a' as i64) + 1,
+        'S' => 1,
+        'E' => ('z' as i64) - ('a' as i64) + 1,
+        c => panic!("invalid char: {}", c),
+    }
+}
+
+/// Shortest path from any of the starting positions to the end, climbing at
+/// most one level up with every step.
+pub fn find_shortest_path(
+    grid: &Grid<char>,
+    starting_pos: &[(usize, usize)],
+) -> anyhow::Result<Vec<(usize, usize)>> {
+    let climbable = |from: &(usize, usize)| {
+        let height = square_height(grid[*from]);
+        grid.neighbours_4(*from)
+            .filter(move |to| square_height(grid[*to]) - height <= 1)
+    };
+    let search = search::bfs(starting_pos.iter().copied(), climbable, |pos| {
+        grid[*pos] == 'E'
+    })?;
+
+    let path = search
+        .goal_path()
+        .ok_or_else(|| anyhow::anyhow!("failed to reach the end"))?;
+    debug!("path from {:?} in {} steps", path[0], path.len() - 1);
+    Ok(path)
+}
+
+/// Parses the heightmap making sure it only has heights and a single start and
+/// end.
+pub fn parse_heightmap(input: &str) -> Result<Grid<char>, ParseError> {
+    let p = Parser::new(DAY, input);
+
+    for line in input.lines() {
+        for (i, c) in line.char_indices() {
+            if !matches!(c, 'a'..='z' | 'S' | 'E') {
+                return Err(p.error(&line[i..i + c.len_utf8()], "unexpected square"));
+            }
+        }
+    }
+    for mark in ['S', 'E'] {
+        if input.matches(mark).count() != 1 {
+            return Err(p.error(input, format!("expected exactly one {:?}", mark)));
+        }
+    }
+
+    parse_to_char_grid(input).map_err(|e| e.with_day(DAY))
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<usize> {
+    let grid = parse_heightmap(input)?;
+
+    let start_pos = grid
+        .iter()
+        .find_map(|(pos, c)| (*c == 'S').then_some(pos))
+        .unwrap();
+
+    Ok(find_shortest_path(&grid, &[start_pos])?.len() - 1)
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<usize> {
+    let grid = parse_heightmap(input)?;
+
+    let start_pos = grid
+        .iter()
+        .filter_map(|(pos, c)| matches!(c, 'S' | 'a').then_some(pos))
+        .collect::<Vec<_>>();
+
+    Ok(find_shortest_path(&grid, &start_pos)?.len() - 1)
+}
+
+const INPUT: &str = r"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("31"), Some("29")],
+}];
+
+#[cfg(test)]
+mod test {
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use super::{find_shortest_path, parse_heightmap, square_height};
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 31);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 29);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1(&INPUT.replace("ryx", "r.x"))
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (12, 2, 5));
+        assert_eq!(err.text, ".");
+
+        let err = task_2(&INPUT.replace("abcryxxl", "abcr"))
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line), (12, 2));
+
+        assert!(task_1(&INPUT.replace('E', "z")).is_err());
+        assert!(task_2(&INPUT.replace("abd", "abS")).is_err());
+    }
+
+    #[test]
+    fn test_path() {
+        let grid = parse_heightmap(INPUT).unwrap();
+        let path = find_shortest_path(&grid, &[(0, 0)]).unwrap();
+        assert_eq!(path.len(), 32);
+        assert_eq!(grid[path[0]], 'S');
+        assert_eq!(grid[path[31]], 'E');
+        for step in path.windows(2) {
+            assert!(grid.neighbours_4(step[0]).any(|p| p == step[1]));
+            assert!(square_height(grid[step[1]]) <= square_height(grid[step[0]]) + 1);
+        }
+    }
+
+    #[test]
+    fn test_unreachable_end() {
+        let err = task_1("Sbz\nabE").expect_err("expected error");
+        assert_eq!(err.to_string(), "failed to reach the end");
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 31);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 29);
+    }
+
+    /// Random heights from a band of letters, so that the end is reachable
+    /// only in some of the maps.
+    fn generate(rng: &mut Rng) -> String {
+        let (rows, cols) = (rng.range(2, 8) as usize, rng.range(2, 10) as usize);
+        let lowest = rng.range(0, 20) as u8;
+        let highest = rng.range(lowest as i64, 25) as u8;
+        let mut grid: Vec<Vec<char>> = (0..rows)
+            .map(|_| {
+                (0..cols)
+                    .map(|_| (b'a' + rng.range(lowest as i64, highest as i64) as u8) as char)
+                    .collect()
+            })
+            .collect();
+
+        let mut cells: Vec<usize> = (0..rows * cols).collect();
+        rng.shuffle(&mut cells);
+        grid[cells[0] / cols][cells[0] % cols] = 'S';
+        grid[cells[1] / cols][cells[1] % cols] = 'E';
+        let lines: Vec<String> = grid.into_iter().map(String::from_iter).collect();
+        lines.join("\n")
+    }
+
+    /// Relaxes distances of all squares until nothing changes, starting from
+    /// squares marked with any of `starts`.
+    fn reference(input: &str, starts: &[u8]) -> Option<usize> {
+        let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let height = |c: u8| match c {
+            b'S' => b'a',
+            b'E' => b'z',
+            c => c,
+        };
+        let cells: Vec<(usize, usize)> = (0..grid.len())
+            .flat_map(|r| (0..grid[0].len()).map(move |c| (r, c)))
+            .collect();
+
+        let mut dist = vec![vec![usize::MAX; grid[0].len()]; grid.len()];
+        for &(r, c) in &cells {
+            if starts.contains(&grid[r][c]) {
+                dist[r][c] = 0;
+            }
+        }
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &(r, c) in &cells {
+                for &(nr, nc) in &cells {
+                    let adjacent = r.abs_diff(nr) + c.abs_diff(nc) == 1;
+                    if adjacent
+                        && dist[r][c] != usize::MAX
+                        && height(grid[nr][nc]) <= height(grid[r][c]) + 1
+                        && dist[r][c] + 1 < dist[nr][nc]
+                    {
+                        dist[nr][nc] = dist[r][c] + 1;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let (r, c) = *cells.iter().find(|(r, c)| grid[*r][*c] == b'E')?;
+        (dist[r][c] != usize::MAX).then_some(dist[r][c])
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).ok(), reference(input, b"S"));
+            assert_eq!(task_2(input).ok(), reference(input, b"Sa"));
+        });
+    }
+}
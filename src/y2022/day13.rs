@@ -0,0 +1,462 @@
+use std::cmp::Ordering;
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::util::read;
+
+const DAY: u32 = 13;
+
+/// Packets compare by the puzzle's ordering, so `==` holds for packets that
+/// differ only in nesting, like `7` and `[7]`. Use [`Packet::is_identical`] to
+/// compare their structure.
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Literal(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    /// Whether both packets have the same values with the same nesting.
+    pub fn is_identical(&self, other: &Packet) -> bool {
+        match (self, other) {
+            (Packet::Literal(a), Packet::Literal(b)) => a == b,
+            (Packet::List(a), Packet::List(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.is_identical(b))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Literal(a), Packet::Literal(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => {
+                // Lists compare element by element, the shorter one first when
+                // all elements are equal.
+                for (a, b) in a.iter().zip(b) {
+                    match a.cmp(b) {
+                        Ordering::Equal => {}
+                        ord => return ord,
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+            (literal @ Packet::Literal(_), list @ Packet::List(_)) => {
+                Packet::List(vec![literal.clone()]).cmp(list)
+            }
+            (list @ Packet::List(_), literal @ Packet::Literal(_)) => {
+                list.cmp(&Packet::List(vec![literal.clone()]))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Token {
+    Literal(u32),
+    ListStart,
+    ListEnd,
+}
+
+/// Splits the packet into tokens checking that it is a single value with
+/// balanced brackets.
+pub fn tokenize_packet(line: &str) -> Result<Vec<Token>, ParseError> {
+    let p = Parser::new(DAY, line);
+    let mut tokens = Vec::new();
+    let mut depth = 0;
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if depth == 0 && !tokens.is_empty() {
+            return Err(p.error(&line[i..], "unexpected data after packet"));
+        }
+        match c {
+            '0'..='9' => {
+                let mut end = i + 1;
+                while let Some((j, c)) = chars.peek() {
+                    if !c.is_ascii_digit() {
+                        break;
+                    }
+                    end = j + 1;
+                    chars.next(); // consume peeked char
+                }
+                tokens.push(Token::Literal(p.parse(&line[i..end])?));
+            }
+            ',' if depth > 0 => {}
+            '[' => {
+                depth += 1;
+                tokens.push(Token::ListStart);
+            }
+            ']' if depth > 0 => {
+                depth -= 1;
+                tokens.push(Token::ListEnd);
+            }
+            _ => return Err(p.error(&line[i..i + c.len_utf8()], "unexpected char")),
+        }
+    }
+
+    if tokens.is_empty() {
+        return Err(p.error(line, "expected packet"));
+    }
+    if depth > 0 {
+        return Err(p.error(&line[line.len()..], "expected \"]\""));
+    }
+    Ok(tokens)
+}
+
+fn parse_list<T: Iterator<Item = Token>>(tokens: &mut T) -> Packet {
+    let mut list = Vec::new();
+
+    while let Some(t) = tokens.next() {
+        match t {
+            Token::Literal(num) => {
+                list.push(Packet::Literal(num));
+            }
+            Token::ListStart => {
+                list.push(parse_list(tokens));
+            }
+            Token::ListEnd => {
+                break;
+            }
+        }
+    }
+
+    Packet::List(list)
+}
+
+pub fn parse_packet(line: &str) -> Result<Packet, ParseError> {
+    let tokens = tokenize_packet(line)?;
+
+    Ok(match tokens[0] {
+        Token::ListStart => parse_list(&mut tokens[1..].iter().copied()),
+        Token::Literal(num) => Packet::Literal(num),
+        Token::ListEnd => unreachable!("tokenizer checks brackets"),
+    })
+}
+
+fn parse_packet_pair(s: &str) -> Result<(Packet, Packet), ParseError> {
+    let p = Parser::new(DAY, s);
+
+    let pp = s
+        .lines()
+        .map(|line| p.within(line, parse_packet(line)))
+        .collect::<Result<Vec<_>, _>>()?;
+    match <[Packet; 2]>::try_from(pp) {
+        Ok([a, b]) => Ok((a, b)),
+        Err(_) => Err(p.error(s, "expected pair of packets")),
+    }
+}
+
+pub fn parse_packet_pairs(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    read::str_blocks(DAY, input)
+        .map(|block| block.within(parse_packet_pair(&block.text())))
+        .collect()
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<usize> {
+    solve_1(parse_packet_pairs(input)?)
+}
+
+pub fn solve_1(packet_pairs: Vec<(Packet, Packet)>) -> anyhow::Result<usize> {
+    let mut right_order_idx_sum: usize = 0;
+
+    for (idx, (a, b)) in packet_pairs.iter().enumerate() {
+        if a < b {
+            right_order_idx_sum += idx + 1;
+        }
+    }
+
+    Ok(right_order_idx_sum)
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<usize> {
+    solve_2(parse_packet_pairs(input)?)
+}
+
+pub fn solve_2(packet_pairs: Vec<(Packet, Packet)>) -> anyhow::Result<usize> {
+    let div_packet1 = Packet::List(vec![Packet::List(vec![Packet::Literal(2)])]);
+    let div_packet2 = Packet::List(vec![Packet::List(vec![Packet::Literal(6)])]);
+
+    let mut all_packets = vec![div_packet1.clone(), div_packet2.clone()];
+
+    for (p1, p2) in packet_pairs {
+        all_packets.push(p1);
+        all_packets.push(p2);
+    }
+
+    all_packets.sort();
+
+    let decoder_key = all_packets
+        .into_iter()
+        .enumerate()
+        .filter(|(_, p)| p.is_identical(&div_packet1) || p.is_identical(&div_packet2))
+        .map(|(idx, _)| idx + 1)
+        .product();
+
+    Ok(decoder_key)
+}
+
+const INPUT: &str = r"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("13"), Some("140")],
+}];
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+
+    use super::task_1;
+    use super::task_2;
+    use super::{parse_packet, Packet, INPUT};
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 13);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 140);
+    }
+
+    #[test]
+    fn test_parse_packet() {
+        assert!(parse_packet("10")
+            .unwrap()
+            .is_identical(&Packet::Literal(10)));
+        assert!(parse_packet("[[],12]")
+            .unwrap()
+            .is_identical(&Packet::List(vec![
+                Packet::List(vec![]),
+                Packet::Literal(12)
+            ])));
+        assert!(!parse_packet("[10]")
+            .unwrap()
+            .is_identical(&Packet::Literal(10)));
+
+        for (line, column) in [("[1,x]", 4), ("[[1]", 5), ("[1]]", 4), ("[1]2", 4), ("", 1)] {
+            let err = parse_packet(line).expect_err(line);
+            assert_eq!((err.line, err.column), (1, column), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1(&INPUT.replace("[[1],[2,3,4]]", "[[1],[2;3,4]]"))
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (13, 4, 8));
+
+        assert!(task_2("[1]\n[2]\n[3]").is_err());
+        assert!(task_2("[1]").is_err());
+    }
+
+    #[test]
+    fn test_ordering() {
+        let packets = [
+            "[1,[2,3]]",
+            "[1,2,3]",
+            "[[1],[2],3]",
+            "[]",
+            "[[]]",
+            "7",
+            "[7]",
+        ]
+        .map(|p| parse_packet(p).unwrap());
+        for a in &packets {
+            assert_eq!(a.cmp(a), Ordering::Equal);
+            assert!(a.is_identical(a));
+            for b in &packets {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{:?} {:?}", a, b);
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{:?} {:?}", a, b);
+            }
+        }
+        assert_eq!(packets[1].cmp(&packets[2]), Ordering::Equal);
+        assert_eq!(packets[5].cmp(&packets[6]), Ordering::Equal);
+        assert!(packets[5] == packets[6] && !packets[5].is_identical(&packets[6]));
+        assert_eq!(packets[0].cmp(&packets[1]), Ordering::Greater);
+        assert_eq!(packets[3].cmp(&packets[4]), Ordering::Less);
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 13);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 140);
+    }
+
+    fn random_list(rng: &mut Rng, depth: usize) -> String {
+        let items: Vec<String> = (0..rng.range(0, 4))
+            .map(|_| match depth < 3 && rng.chance(30) {
+                true => random_list(rng, depth + 1),
+                false => rng.range(0, 10).to_string(),
+            })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+
+    /// Same packet with some numbers wrapped in a list, which compares equal.
+    fn wrap_numbers(rng: &mut Rng, packet: &str) -> String {
+        packet
+            .chars()
+            .map(|c| match c.is_ascii_digit() && rng.chance(50) {
+                true => format!("[{}]", c),
+                false => c.to_string(),
+            })
+            .collect()
+    }
+
+    /// Pairs of packets in any order, including duplicates and different
+    /// packets that compare equal. None is equal to a divider packet as their
+    /// position after sorting would be ambiguous.
+    fn generate(rng: &mut Rng) -> String {
+        let dividers = [value("[[2]]"), value("[[6]]")];
+        let count = rng.range(1, 12) as usize;
+        let mut pairs = Vec::new();
+        while pairs.len() < count {
+            let a = random_list(rng, 0);
+            let b = match rng.range(0, 4) {
+                0 => a.clone(),
+                1 => wrap_numbers(rng, &a),
+                _ => random_list(rng, 0),
+            };
+            if [value(&a), value(&b)]
+                .iter()
+                .all(|v| dividers.iter().all(|d| compare(v, d) != Ordering::Equal))
+            {
+                pairs.push(format!("{}\n{}", a, b));
+            }
+        }
+        pairs.join("\n\n")
+    }
+
+    enum Value {
+        Int(u32),
+        List(Vec<Value>),
+    }
+
+    fn value(s: &str) -> Value {
+        fn parse(s: &[u8], i: &mut usize) -> Value {
+            if s[*i] != b'[' {
+                let start = *i;
+                while *i < s.len() && s[*i].is_ascii_digit() {
+                    *i += 1;
+                }
+                return Value::Int(std::str::from_utf8(&s[start..*i]).unwrap().parse().unwrap());
+            }
+            *i += 1;
+            let mut items = Vec::new();
+            while s[*i] != b']' {
+                items.push(parse(s, i));
+                if s[*i] == b',' {
+                    *i += 1;
+                }
+            }
+            *i += 1;
+            Value::List(items)
+        }
+        parse(s.as_bytes(), &mut 0)
+    }
+
+    fn compare(a: &Value, b: &Value) -> Ordering {
+        match (a, b) {
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Int(a), list) => compare(&Value::List(vec![Value::Int(*a)]), list),
+            (list, Value::Int(b)) => compare(list, &Value::List(vec![Value::Int(*b)])),
+            (Value::List(a), Value::List(b)) => {
+                for (a, b) in a.iter().zip(b) {
+                    match compare(a, b) {
+                        Ordering::Equal => {}
+                        ord => return ord,
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+        }
+    }
+
+    fn reference_1(input: &str) -> usize {
+        input
+            .split("\n\n")
+            .enumerate()
+            .filter(|(_, pair)| {
+                let (a, b) = pair.split_once('\n').unwrap();
+                compare(&value(a), &value(b)) == Ordering::Less
+            })
+            .map(|(i, _)| i + 1)
+            .sum()
+    }
+
+    /// Counts packets smaller than each divider instead of sorting.
+    fn reference_2(input: &str) -> usize {
+        let packets: Vec<Value> = input.lines().filter(|l| !l.is_empty()).map(value).collect();
+        let position = |divider: &str| {
+            let divider = value(divider);
+            packets
+                .iter()
+                .filter(|p| compare(p, &divider) == Ordering::Less)
+                .count()
+        };
+        (position("[[2]]") + 1) * (position("[[6]]") + 2)
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            for pair in input.split("\n\n") {
+                let (a, b) = pair.split_once('\n').unwrap();
+                let expected = compare(&value(a), &value(b));
+                let (a, b) = (parse_packet(a).unwrap(), parse_packet(b).unwrap());
+                assert_eq!(a.cmp(&b), expected, "{}", pair);
+                assert_eq!(b.cmp(&a), expected.reverse(), "{}", pair);
+            }
+            assert_eq!(task_1(input).unwrap(), reference_1(input));
+            assert_eq!(task_2(input).unwrap(), reference_2(input));
+        });
+    }
+}
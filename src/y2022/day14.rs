@@ -0,0 +1,322 @@
+use crate::cancel;
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::util::geom::{Direction8, Point};
+use crate::util::Grid;
+
+const DAY: u32 = 14;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Sand,
+    Rock,
+    Air,
+    Abyss,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RockLine {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl RockLine {
+    pub fn new_from_point(start: Point, end: Point) -> Self {
+        // We assume that line is always horizontal or vertical.
+        Self {
+            start: Point::new(start.x.min(end.x), start.y.min(end.y)),
+            end: Point::new(start.x.max(end.x), start.y.max(end.y)),
+        }
+    }
+}
+
+pub fn parse_rock_lines(line: &str) -> Result<Vec<RockLine>, ParseError> {
+    let p = Parser::new(DAY, line);
+
+    let points = line
+        .split(" -> ")
+        .map(|point| {
+            let (x, y) = p.split_once(point, ",")?;
+            let parsed = Point::new(p.parse(x)?, p.parse(y)?);
+            if parsed.x < 0 || parsed.y < 0 {
+                return Err(p.error(point, "expected non-negative coordinates"));
+            }
+            Ok((point, parsed))
+        })
+        .collect::<Result<Vec<(&str, Point)>, ParseError>>()?;
+
+    let mut rock_lines = Vec::new();
+
+    for point_slice in points.windows(2) {
+        let ((_, start), (text, end)) = (point_slice[0], point_slice[1]);
+        if start.x != end.x && start.y != end.y {
+            return Err(p.error(text, "expected horizontal or vertical line"));
+        }
+        rock_lines.push(RockLine::new_from_point(start, end));
+    }
+
+    Ok(rock_lines)
+}
+
+pub fn run_simulation(
+    rock_lines: &[RockLine],
+    max_x: isize,
+    max_y: isize,
+) -> anyhow::Result<usize> {
+    let mut grid = Grid::new(max_x as usize + 1, max_y as usize + 1, Tile::Air);
+
+    for rl in rock_lines {
+        for y in rl.start.y..=rl.end.y {
+            for x in rl.start.x..=rl.end.x {
+                *grid.get_mut((x, y)).expect("rock outside of the grid") = Tile::Rock;
+            }
+        }
+    }
+
+    let mut sand_in_abyss = false;
+    let mut sand_down = 0;
+
+    let source = Point::new(500, 0);
+    let mut sand_in_air = source;
+
+    let move_options = [
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::DownRight,
+    ];
+
+    while !sand_in_abyss {
+        let mut moved = false;
+        for opt in move_options {
+            let next_pos = sand_in_air + opt.vec();
+            match grid.get(next_pos).copied().unwrap_or(Tile::Abyss) {
+                Tile::Sand | Tile::Rock => {
+                    continue;
+                }
+                Tile::Air => {
+                    moved = true;
+                    sand_in_air = next_pos;
+                    break;
+                }
+                Tile::Abyss => {
+                    sand_in_abyss = true;
+                    break;
+                }
+            }
+        }
+        if sand_in_abyss {
+            break;
+        }
+        if !moved {
+            cancel::check()?;
+            *grid.get_mut(sand_in_air).unwrap() = Tile::Sand;
+            sand_down += 1;
+            if grid.get(source) != Some(&Tile::Air) {
+                break;
+            }
+
+            sand_in_air = source;
+        }
+    }
+
+    Ok(sand_down)
+}
+
+pub fn parse_rocks(input: &str) -> Result<Vec<RockLine>, ParseError> {
+    let p = Parser::new(DAY, input);
+
+    let mut rock_lines = Vec::new();
+    for line in input.lines() {
+        rock_lines.extend(p.within(line, parse_rock_lines(line))?);
+    }
+    if rock_lines.is_empty() {
+        return Err(p.error(input, "expected at least one rock line"));
+    }
+
+    Ok(rock_lines)
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<usize> {
+    solve_1(parse_rocks(input)?)
+}
+
+pub fn solve_1(rock_lines: Vec<RockLine>) -> anyhow::Result<usize> {
+    let max_x = rock_lines
+        .iter()
+        .map(|line| line.end.x)
+        .max()
+        .unwrap()
+        .max(500);
+
+    let max_y = rock_lines.iter().map(|line| line.end.y).max().unwrap();
+
+    run_simulation(&rock_lines, max_x, max_y)
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<usize> {
+    solve_2(parse_rocks(input)?)
+}
+
+// TODO: I suppose this could be done more optimally without needing to run the
+// whole simulation.
+pub fn solve_2(mut rock_lines: Vec<RockLine>) -> anyhow::Result<usize> {
+    let max_x = rock_lines
+        .iter()
+        .map(|line| line.end.x)
+        .max()
+        .unwrap()
+        .max(500);
+
+    let max_y = rock_lines.iter().map(|line| line.end.y).max().unwrap();
+
+    let max_y = max_y + 2;
+
+    // This is pretty ugly workaround to just add some value to max_x to make
+    // sure we do not overflow on the right side, otherwise we would need to
+    // support some kind of dynamic resizing of the grid.
+    // We use 500+max_y because we should not be able to go over that since sand
+    // will not be able to fall down priro to reaching that point (at least I think so :shrug:).
+    let max_x = max_x.max(500 + max_y);
+
+    rock_lines.push(RockLine {
+        start: Point::new(0, max_y),
+        end: Point::new(max_x, max_y),
+    });
+
+    run_simulation(&rock_lines, max_x, max_y)
+}
+
+const INPUT: &str = r"498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "1",
+    input: INPUT,
+    expected: [Some("24"), Some("93")],
+}];
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::task_1;
+    use super::task_2;
+    use super::INPUT;
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::{normalize_input, windows_input};
+
+    #[test]
+    fn test_1() {
+        assert_eq!(task_1(INPUT).expect("failed to run 1"), 24);
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(task_2(INPUT).expect("failed to run 2"), 93);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1(&INPUT.replace("502,9", "503,8"))
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.day, err.line, err.column), (14, 2, 19));
+        assert_eq!(err.text, "503,8");
+
+        let err = task_2(&INPUT.replace("496,6", "496 6"))
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (1, 19));
+
+        let err = task_1(&INPUT.replace("494,9", "-494,9"))
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (2, 28));
+
+        assert!(task_1("").is_err());
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = normalize_input(&windows_input(INPUT));
+        assert_eq!(task_1(&input).expect("failed to run 1"), 24);
+        assert_eq!(task_2(&input).expect("failed to run 2"), 93);
+    }
+
+    /// Paths of alternating horizontal and vertical lines around the sand
+    /// source.
+    fn generate(rng: &mut Rng) -> String {
+        let paths: Vec<String> = (0..rng.range(1, 5))
+            .map(|_| {
+                let mut point = (rng.range(490, 510), rng.range(1, 12));
+                let mut points = vec![point];
+                let mut horizontal = rng.chance(50);
+                for _ in 0..rng.range(1, 4) {
+                    point = match horizontal {
+                        true => (rng.range(488, 512), point.1),
+                        false => (point.0, rng.range(1, 14)),
+                    };
+                    points.push(point);
+                    horizontal = !horizontal;
+                }
+                let points: Vec<String> =
+                    points.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+                points.join(" -> ")
+            })
+            .collect();
+        paths.join("\n")
+    }
+
+    /// Drops sand grain by grain until one falls below the lowest rock or,
+    /// with `floor`, until the source is blocked.
+    fn reference(input: &str, floor: bool) -> usize {
+        let mut blocked = HashSet::new();
+        for line in input.lines() {
+            let points: Vec<(i64, i64)> = line
+                .split(" -> ")
+                .map(|p| {
+                    let (x, y) = p.split_once(',').unwrap();
+                    (x.parse().unwrap(), y.parse().unwrap())
+                })
+                .collect();
+            for w in points.windows(2) {
+                for x in w[0].0.min(w[1].0)..=w[0].0.max(w[1].0) {
+                    for y in w[0].1.min(w[1].1)..=w[0].1.max(w[1].1) {
+                        blocked.insert((x, y));
+                    }
+                }
+            }
+        }
+        let lowest = blocked.iter().map(|p| p.1).max().unwrap();
+
+        let mut grains = 0;
+        while !blocked.contains(&(500, 0)) {
+            let (mut x, mut y) = (500, 0);
+            loop {
+                let next = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)]
+                    .into_iter()
+                    .find(|p| !blocked.contains(p) && (!floor || p.1 < lowest + 2));
+                match next {
+                    Some(_) if !floor && y > lowest => return grains,
+                    Some(p) => (x, y) = p,
+                    None => break,
+                }
+            }
+            blocked.insert((x, y));
+            grains += 1;
+        }
+        grains
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            assert_eq!(task_1(input).unwrap(), reference(input, false));
+            assert_eq!(task_2(input).unwrap(), reference(input, true));
+        });
+    }
+}
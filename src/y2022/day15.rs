@@ -0,0 +1,382 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use crate::parse::{ParseError, Parser};
+use crate::solutions::Example;
+use crate::util::geom::Point;
+use crate::util::IntervalSet;
+
+const DAY: u32 = 15;
+
+pub fn position_from_str(p: &Parser, s: &str) -> Result<Point, ParseError> {
+    let (x, y) = p.split_once(s, ", ")?;
+    Ok(Point::new(
+        p.parse(p.strip_prefix(x, "x=")?)?,
+        p.parse(p.strip_prefix(y, "y=")?)?,
+    ))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sensor {
+    pub position: Point,
+    // Distance to the closest beacon
+    pub dist: isize,
+    pub beacon: Point,
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let s = p.strip_prefix(s, "Sensor at ")?;
+        let (sensor_p, beacon_p) = p.split_once(s, ": closest beacon is at ")?;
+
+        let sensor_position = position_from_str(&p, sensor_p)?;
+        let beacon_position = position_from_str(&p, beacon_p)?;
+        Ok(Self {
+            position: sensor_position,
+            dist: sensor_position.manhattan(beacon_position),
+            beacon: beacon_position,
+        })
+    }
+}
+
+/// Positions of the row covered by at least one sensor.
+pub fn find_empty_ranges(sensors: &[Sensor], row: isize) -> IntervalSet {
+    sensors
+        .iter()
+        .map(|s| {
+            let (x, d) = (s.position.x, (s.position.y - row).abs());
+            x - (s.dist - d)..=x + (s.dist - d)
+        })
+        .collect()
+}
+
+/// Merges `(start, end)` ranges into sorted disjoint ones. Ranges that only
+/// touch are merged too, see [`IntervalSet`].
+pub fn flatten_ranges(ranges: &[(isize, isize)]) -> Vec<(isize, isize)> {
+    let set: IntervalSet = ranges.iter().map(|&(s, e)| s..=e).collect();
+    set.iter().map(|r| r.into_inner()).collect()
+}
+
+pub fn parse_sensors(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let p = Parser::new(DAY, input);
+
+    input
+        .lines()
+        .map(|line| p.within(line, Sensor::from_str(line)))
+        .collect()
+}
+
+/// Row checked by the first task.
+pub const ROW: isize = 2000000;
+/// Upper bound of both coordinates of the distress beacon in the second task.
+pub const MAX_COORD: isize = 4000000;
+
+/// Counts positions in the row where a beacon cannot be present, `None` if
+/// there are too many to fit in `isize`.
+pub fn count_empty(sensors: &[Sensor], row: isize) -> Option<isize> {
+    let covered = find_empty_ranges(sensors, row).coverage();
+
+    let mut beacons: Vec<_> = sensors
+        .iter()
+        .map(|s| s.beacon)
+        .filter(|b| b.y == row)
+        .collect();
+    beacons.sort_unstable();
+    beacons.dedup();
+
+    isize::try_from(covered - beacons.len() as u128).ok()
+}
+
+pub fn task_1(input: &str) -> anyhow::Result<isize> {
+    let sensors = parse_sensors(input)?;
+
+    count_empty(&sensors, ROW).ok_or_else(|| anyhow::anyhow!("too many positions to count"))
+}
+
+/// Finds the only position with both coordinates in `0..=max` that no sensor
+/// covers.
+pub fn find_distress_beacon(sensors: &[Sensor], max: isize) -> Option<Point> {
+    let mut m1 = BTreeMap::<isize, isize>::new();
+    let mut m2 = BTreeMap::<isize, isize>::new();
+
+    for s in sensors {
+        let Point { x, y } = s.position;
+        *m1.entry(y - x + s.dist + 1).or_default() += 1;
+        *m1.entry(y - x - s.dist - 1).or_default() += 1;
+        *m2.entry(x + y + s.dist + 1).or_default() += 1;
+        *m2.entry(x + y - s.dist - 1).or_default() += 1;
+    }
+
+    let m1 = m1
+        .into_iter()
+        .filter_map(|(k, v)| if v > 1 { Some(k) } else { None });
+
+    let m2 = m2
+        .into_iter()
+        .filter_map(|(k, v)| if v > 1 { Some(k) } else { None })
+        .collect::<Vec<_>>();
+
+    for a in m1 {
+        for b in &m2 {
+            let x = (b - a) / 2;
+            let y = (a + b) / 2;
+            if x.min(y) < 0 || x.max(y) > max {
+                continue;
+            }
+
+            let position = Point::new(x, y);
+            if sensors
+                .iter()
+                .all(|s| s.position.manhattan(position) > s.dist)
+            {
+                return Some(position);
+            }
+        }
+    }
+
+    // The position is not between borders of two pairs of sensors, for
+    // example at the edge of the area.
+    scan_rows(sensors, max)
+}
+
+/// Looks for the first gap in sensor coverage row by row.
+pub fn scan_rows(sensors: &[Sensor], max: isize) -> Option<Point> {
+    (0..=max).find_map(|row| {
+        let gap = find_empty_ranges(sensors, row).gaps(0..=max).next()?;
+        Some(Point::new(*gap.start(), row))
+    })
+}
+
+pub fn tuning_frequency(position: Point) -> isize {
+    position.x * 4000000 + position.y
+}
+
+pub fn task_2(input: &str) -> anyhow::Result<isize> {
+    let sensors = parse_sensors(input)?;
+
+    match find_distress_beacon(&sensors, MAX_COORD) {
+        Some(position) => Ok(tuning_frequency(position)),
+        None => anyhow::bail!("expected to find solution"),
+    }
+}
+
+/// Scans all rows of the area, used to cross-check the search along sensor
+/// borders in [`task_2`].
+pub fn naive_2(input: &str) -> anyhow::Result<isize> {
+    let sensors = parse_sensors(input)?;
+
+    match scan_rows(&sensors, MAX_COORD) {
+        Some(position) => Ok(tuning_frequency(position)),
+        None => anyhow::bail!("expected to find solution"),
+    }
+}
+
+// No example is registered as the tasks use fixed row and bounds of the real
+// puzzle, tests check the example with the ones from the puzzle description.
+pub const EXAMPLES: &[Example] = &[];
+
+#[cfg(test)]
+mod test {
+    use super::{
+        count_empty, find_distress_beacon, find_empty_ranges, flatten_ranges, parse_sensors,
+        scan_rows, task_1, tuning_frequency,
+    };
+    use crate::parse::ParseError;
+    use crate::testing::{for_random_inputs, Rng, CASES};
+    use crate::util::geom::Point;
+    use crate::util::{normalize_input, windows_input};
+
+    const SENSORS: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16";
+
+    const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    // Row and bounds used by the example in the puzzle description.
+    const ROW: isize = 10;
+    const MAX_COORD: isize = 20;
+
+    #[test]
+    fn test_1() {
+        let sensors = parse_sensors(INPUT).expect("failed to parse");
+        assert_eq!(count_empty(&sensors, ROW), Some(26));
+    }
+
+    #[test]
+    fn test_2() {
+        let sensors = parse_sensors(INPUT).expect("failed to parse");
+        let position = find_distress_beacon(&sensors, MAX_COORD);
+        assert_eq!(position, Some(Point::new(14, 11)));
+        assert_eq!(tuning_frequency(position.unwrap()), 56000011);
+    }
+
+    #[test]
+    fn test_beacon_at_edge() {
+        // Only (0, 0) is not covered, which is not between borders of sensors.
+        let sensors = parse_sensors(
+            "Sensor at x=3, y=3: closest beacon is at x=3, y=8
+Sensor at x=2, y=5: closest beacon is at x=2, y=0",
+        )
+        .unwrap();
+        assert_eq!(find_distress_beacon(&sensors, 4), Some(Point::ORIGIN));
+    }
+
+    #[test]
+    fn test_adjacent_ranges() {
+        let sensors = parse_sensors(
+            "Sensor at x=2, y=0: closest beacon is at x=3, y=0
+Sensor at x=5, y=0: closest beacon is at x=6, y=0",
+        )
+        .unwrap();
+        let ranges = find_empty_ranges(&sensors, 0);
+        assert_eq!(ranges.iter().collect::<Vec<_>>(), vec![1..=6]);
+        assert_eq!(count_empty(&sensors, 0), Some(4));
+        assert_eq!(flatten_ranges(&[(4, 6), (1, 3), (2, 2)]), vec![(1, 6)]);
+        assert_eq!(flatten_ranges(&[(1, 2), (5, 5)]), vec![(1, 2), (5, 5)]);
+    }
+
+    #[test]
+    fn test_parse_sensors() {
+        let sensors = parse_sensors(SENSORS).expect("failed to parse");
+        assert_eq!(
+            sensors
+                .iter()
+                .map(|s| (s.position.x, s.position.y, s.dist))
+                .collect::<Vec<_>>(),
+            vec![(2, 18, 7), (9, 16, 1)]
+        );
+        assert_eq!(sensors[0].beacon, Point::new(-2, 15));
+
+        let err = parse_sensors(&SENSORS.replace("x=10", "x=1O")).unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (15, 2, 45));
+        assert_eq!(err.text, "1O");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = task_1("Sensor at x=2, y=18: beacon at x=-2, y=15")
+            .expect_err("expected parse error")
+            .downcast::<ParseError>()
+            .expect("expected ParseError");
+        assert_eq!((err.line, err.column), (1, 11));
+    }
+
+    #[test]
+    fn test_crlf() {
+        let sensors = parse_sensors(&normalize_input(&windows_input(SENSORS))).unwrap();
+        assert_eq!(sensors.len(), 2);
+        assert_eq!(
+            (sensors[1].position, sensors[1].dist),
+            (Point::new(9, 16), 1)
+        );
+    }
+
+    /// Sensors covering every position in the example bounds but one. Each
+    /// sensor reaches just short of the hidden position.
+    fn generate(rng: &mut Rng) -> String {
+        let max = MAX_COORD as i64;
+        let hidden = (rng.range(0, max), rng.range(0, max));
+        let mut sensors: Vec<((i64, i64), i64)> = Vec::new();
+        let mut lines = Vec::new();
+        let covered = |sensors: &[((i64, i64), i64)], (x, y): (i64, i64)| {
+            sensors
+                .iter()
+                .any(|((sx, sy), d)| (sx - x).abs() + (sy - y).abs() <= *d)
+        };
+
+        loop {
+            let uncovered: Vec<(i64, i64)> = (0..=max)
+                .flat_map(|y| (0..=max).map(move |x| (x, y)))
+                .filter(|p| *p != hidden && !covered(&sensors, *p))
+                .collect();
+            if uncovered.is_empty() {
+                return lines.join("\n");
+            }
+
+            // Moving the sensor away from the hidden position keeps the
+            // chosen position in its range.
+            let (x, y) = *rng.choose(&uncovered);
+            let k = rng.range(1, 2);
+            let sensor = (x + (x - hidden.0) * k, y + (y - hidden.1) * k);
+            let dist = (sensor.0 - hidden.0).abs() + (sensor.1 - hidden.1).abs() - 1;
+            let dx = rng.range(-dist, dist);
+            let dy = (dist - dx.abs()) * if rng.chance(50) { 1 } else { -1 };
+            let beacon = (sensor.0 + dx, sensor.1 + dy);
+
+            sensors.push((sensor, dist));
+            lines.push(format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.0, sensor.1, beacon.0, beacon.1
+            ));
+        }
+    }
+
+    /// Position of a sensor and distance it covers.
+    type Reach = ((isize, isize), isize);
+
+    /// Reach of all sensors and positions of all beacons.
+    fn reference_parse(input: &str) -> (Vec<Reach>, Vec<(isize, isize)>) {
+        let numbers = |line: &str| -> Vec<isize> {
+            line.split(|c: char| c != '-' && !c.is_ascii_digit())
+                .filter_map(|n| n.parse().ok())
+                .collect()
+        };
+        input
+            .lines()
+            .map(|line| {
+                let n = numbers(line);
+                (
+                    ((n[0], n[1]), (n[0] - n[2]).abs() + (n[1] - n[3]).abs()),
+                    (n[2], n[3]),
+                )
+            })
+            .unzip()
+    }
+
+    fn in_range(sensors: &[Reach], (x, y): (isize, isize)) -> bool {
+        sensors
+            .iter()
+            .any(|((sx, sy), d)| (sx - x).abs() + (sy - y).abs() <= *d)
+    }
+
+    fn reference_1(input: &str, row: isize) -> isize {
+        let (sensors, beacons) = reference_parse(input);
+        let min_x = sensors.iter().map(|((x, _), d)| x - d).min().unwrap();
+        let max_x = sensors.iter().map(|((x, _), d)| x + d).max().unwrap();
+        (min_x..=max_x)
+            .filter(|&x| in_range(&sensors, (x, row)) && !beacons.contains(&(x, row)))
+            .count() as isize
+    }
+
+    fn reference_2(input: &str, max: isize) -> Option<(isize, isize)> {
+        let (sensors, _) = reference_parse(input);
+        (0..=max)
+            .flat_map(|y| (0..=max).map(move |x| (x, y)))
+            .find(|p| !in_range(&sensors, *p))
+    }
+
+    #[test]
+    fn test_random() {
+        for_random_inputs(CASES, generate, |input| {
+            let sensors = parse_sensors(input).unwrap();
+            assert_eq!(count_empty(&sensors, ROW), Some(reference_1(input, ROW)));
+            let expected = reference_2(input, MAX_COORD).map(Point::from);
+            assert_eq!(find_distress_beacon(&sensors, MAX_COORD), expected);
+            assert_eq!(scan_rows(&sensors, MAX_COORD), expected);
+        });
+    }
+}
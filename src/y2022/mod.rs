@@ -0,0 +1,56 @@
+//! Solutions of Advent of Code 2022.
+
+use crate::solutions::{solution, Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+pub const YEAR: u32 = 2022;
+
+/// All solutions of the year ordered by day and part. New days only need to
+/// be added here.
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(YEAR, 1, 1, day01::day_1_1),
+    solution!(YEAR, 1, 2, day01::day_1_2),
+    solution!(YEAR, 2, 1, day02::day_2_1),
+    solution!(YEAR, 2, 2, day02::day_2_2),
+    solution!(YEAR, 3, 1, day03::task_1),
+    solution!(YEAR, 3, 2, day03::task_2),
+    solution!(YEAR, 4, 1, day04::task_1),
+    solution!(YEAR, 4, 2, day04::task_2),
+    solution!(YEAR, 5, 1, day05::task_1),
+    solution!(YEAR, 5, 2, day05::task_2),
+    solution!(YEAR, 6, 1, day06::task_1),
+    solution!(YEAR, 6, 2, day06::task_2),
+    solution!(YEAR, 7, 1, day07::task_1, day07::parse_fs_tree => day07::solve_1),
+    solution!(YEAR, 7, 2, day07::task_2, day07::parse_fs_tree => day07::solve_2),
+    solution!(YEAR, 8, 1, day08::task_1, naive: day08::naive_1),
+    solution!(YEAR, 8, 2, day08::task_2),
+    solution!(YEAR, 9, 1, day09::task_1),
+    solution!(YEAR, 9, 2, day09::task_2),
+    solution!(YEAR, 10, 1, day10::task_1),
+    solution!(YEAR, 10, 2, day10::task_2),
+    solution!(YEAR, 11, 1, day11::task_1, day11::parse_monkeys => day11::solve_1),
+    solution!(YEAR, 11, 2, day11::task_2, day11::parse_monkeys => day11::solve_2, naive: day11::naive_2),
+    solution!(YEAR, 12, 1, day12::task_1),
+    solution!(YEAR, 12, 2, day12::task_2),
+    solution!(YEAR, 13, 1, day13::task_1, day13::parse_packet_pairs => day13::solve_1),
+    solution!(YEAR, 13, 2, day13::task_2, day13::parse_packet_pairs => day13::solve_2),
+    solution!(YEAR, 14, 1, day14::task_1, day14::parse_rocks => day14::solve_1),
+    solution!(YEAR, 14, 2, day14::task_2, day14::parse_rocks => day14::solve_2),
+    solution!(YEAR, 15, 1, day15::task_1),
+    solution!(YEAR, 15, 2, day15::task_2, naive: day15::naive_2),
+];